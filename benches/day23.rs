@@ -0,0 +1,62 @@
+//! Times the day23 searches on the example burrow and its unfolded version, the board of part
+//! 2. Run with `cargo bench --bench day23`.
+//!
+//! Every search runs on the packed `Board`. Dijkstra is the baseline, it's the search day23 used
+//! before the heuristic was added, and the other runs show what the heuristic and the pruning of
+//! deadlocked states save on top of it. The boards from before they were packed into a `u128`
+//! aren't in the tree anymore, so the gain from packing them isn't measured here.
+
+use std::time::{Duration, Instant};
+
+use aoc2021::day23::{parse_burrow, Pruning, FOLDED_ROWS};
+use aoc2021::search;
+
+const EXAMPLE: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+const RUNS: u32 = 5;
+
+/// Runs `f` a few times after a warm-up run and prints the fastest and the average time.
+fn bench<T, F>(name: &str, f: F)
+where
+    F: Fn() -> T,
+{
+    f();
+
+    let times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+
+    println!(
+        "{:<24} fastest {:>9.2?}, average {:>9.2?}",
+        name,
+        times.iter().min().unwrap(),
+        times.iter().sum::<Duration>() / RUNS
+    );
+}
+
+fn main() {
+    let burrow = parse_burrow(EXAMPLE).unwrap();
+    let unfolded = burrow.unfold(&FOLDED_ROWS).unwrap();
+
+    bench("part 1, dijkstra", || search::dijkstra(&burrow));
+    bench("part 1, a*", || search::a_star(&burrow));
+    bench("part 2, dijkstra", || search::dijkstra(&unfolded));
+    bench("part 2, a*", || search::a_star(&unfolded));
+    bench("part 2, a*, pruned", || {
+        search::a_star(&Pruning::new(&unfolded))
+    });
+
+    for (name, burrow) in [("part 1", &burrow), ("part 2", &unfolded)] {
+        let pruning = Pruning::new(burrow);
+        search::a_star(&pruning);
+        println!("{}: pruned {} deadlocked states", name, pruning.pruned());
+    }
+}
@@ -0,0 +1,187 @@
+use std::env;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::process;
+
+use aoc2021::parse::ParseError;
+
+const USAGE: &str = "usage: aoc <day> [--part <1|2>] [--input <file|->]
+
+Runs the solution of the given day. Reads `inputs/dayNN.txt` unless an input file is given, `-`
+reads the input from stdin.";
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    day: u8,
+    part: Option<u8>,
+    input: Option<String>,
+}
+
+fn parse_args<I>(mut args: I) -> Result<Args, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                let value = args.next().ok_or("missing value for --part")?;
+                part = match value.as_str() {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    _ => return Err(format!("invalid part '{}', expected 1 or 2", value)),
+                };
+            }
+            "-i" | "--input" => {
+                input = Some(args.next().ok_or("missing value for --input")?);
+            }
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u8>()
+                        .map_err(|_| format!("invalid day '{}'", arg))?,
+                );
+            }
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(Args {
+        day: day.ok_or("missing day")?,
+        part,
+        input,
+    })
+}
+
+fn input_path(args: &Args) -> String {
+    match args.input.as_deref() {
+        Some("-") => "<stdin>".to_string(),
+        Some(path) => path.to_string(),
+        None => format!("inputs/day{:02}.txt", args.day),
+    }
+}
+
+fn read_input(args: &Args) -> Result<String, String> {
+    let path = input_path(args);
+
+    if args.input.as_deref() == Some("-") {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("couldn't read stdin: {}", e))?;
+        return Ok(input);
+    }
+
+    read_to_string(&path).map_err(|e| format!("couldn't read {}: {}", path, e))
+}
+
+/// Formats a parse error like rustc does, showing the offending line with the token underlined.
+fn diagnostic(error: &ParseError, path: &str, input: &str) -> String {
+    let line = input.lines().nth(error.line - 1).unwrap_or("");
+    let line_number = error.line.to_string();
+    let padding = " ".repeat(line_number.len());
+
+    let found = if error.token.is_empty() {
+        "end of line".to_string()
+    } else {
+        format!("'{}'", error.token)
+    };
+
+    format!(
+        "error: {}, found {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+        error.message,
+        found,
+        padding,
+        path,
+        error.line,
+        error.column,
+        padding,
+        line_number,
+        line,
+        padding,
+        " ".repeat(error.column - 1),
+        "^".repeat(error.token.chars().count().max(1)),
+    )
+}
+
+fn main() {
+    if env::args().any(|a| a == "-h" || a == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {}\n\n{}", e, USAGE);
+        process::exit(2);
+    });
+
+    let input = read_input(&args).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    });
+
+    match aoc2021::solve_day(args.day, &input, args.part) {
+        Some(Ok(answers)) => {
+            for (part, answer) in answers {
+                println!("Part {}: {}", part, answer);
+            }
+        }
+        Some(Err(e)) => {
+            eprintln!("{}", diagnostic(&e, &input_path(&args), &input));
+            process::exit(1);
+        }
+        None => {
+            eprintln!("error: there's no solution for day {}", args.day);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            args(&["15", "--part", "2", "--input", "foo.txt"]),
+            Ok(Args {
+                day: 15,
+                part: Some(2),
+                input: Some("foo.txt".to_string()),
+            })
+        );
+        assert_eq!(
+            args(&["-i", "-", "3"]),
+            Ok(Args {
+                day: 3,
+                part: None,
+                input: Some("-".to_string()),
+            })
+        );
+        assert!(args(&[]).is_err());
+        assert!(args(&["15", "--part", "3"]).is_err());
+        assert!(args(&["15", "16"]).is_err());
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let input = "forward 5\ndown 5\nforward x1\n";
+        let error = aoc2021::solve_day(2, input, None).unwrap().unwrap_err();
+
+        assert_eq!(
+            diagnostic(&error, "foo.txt", input),
+            "error: expected a number, found 'x1'
+ --> foo.txt:3:9
+  |
+3 | forward x1
+  |         ^^"
+        );
+    }
+}
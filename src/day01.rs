@@ -0,0 +1,34 @@
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, |l| parse::number(l, l))
+    }
+
+    fn part_1(values: &Self::Input) -> i64 {
+        count_increases(values.iter().copied())
+    }
+
+    fn part_2(values: &Self::Input) -> i64 {
+        let sums = values.windows(3).map(|w| w.iter().sum::<i64>());
+
+        count_increases(sums)
+    }
+}
+
+pub fn count_increases<I>(iter: I) -> i64
+where
+    I: Iterator<Item = i64> + Clone,
+{
+    iter.clone()
+        .zip(iter.skip(1))
+        .map(|(old, new)| if old < new { 1 } else { 0 })
+        .sum::<i64>()
+}
@@ -0,0 +1,58 @@
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day02;
+
+pub enum Command {
+    Forward(i64),
+    Down(i64),
+    Up(i64),
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, parse_line)
+    }
+
+    fn part_1(commands: &Self::Input) -> i64 {
+        let result = commands.iter().fold((0, 0), |(hor, dep), c| match c {
+            Command::Forward(n) => (hor + n, dep),
+            Command::Down(n) => (hor, dep + n),
+            Command::Up(n) => (hor, dep - n),
+        });
+
+        result.0 * result.1
+    }
+
+    fn part_2(commands: &Self::Input) -> i64 {
+        let result = commands
+            .iter()
+            .fold((0, 0, 0), |(hor, dep, aim), c| match c {
+                Command::Forward(n) => (hor + n, dep + aim * n, aim),
+                Command::Down(n) => (hor, dep, aim + n),
+                Command::Up(n) => (hor, dep, aim - n),
+            });
+
+        result.0 * result.1
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<Command, ParseError> {
+    let (command, n) = parse::split_once(line, line, " ")?;
+    let n = parse::number(line, n)?;
+
+    match command {
+        "forward" => Ok(Command::Forward(n)),
+        "down" => Ok(Command::Down(n)),
+        "up" => Ok(Command::Up(n)),
+        _ => Err(ParseError::new(
+            line,
+            command,
+            "expected 'forward', 'down' or 'up'",
+        )),
+    }
+}
@@ -0,0 +1,184 @@
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rows: Vec<String> = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            let width = rows.first().map(String::len);
+            rows.push(parse_row(line, width).map_err(|e| e.on_line(i + 1))?);
+        }
+
+        if rows.is_empty() {
+            return Err(ParseError::end_of_input(input, "expected at least one row"));
+        }
+
+        Ok(rows)
+    }
+
+    fn part_1(lines: &Self::Input) -> u32 {
+        let (gamma, epsilon) = gamma_epsilon(lines);
+
+        gamma * epsilon
+    }
+
+    fn part_2(lines: &Self::Input) -> u32 {
+        let bit_len = lines[0].len();
+
+        let numbers: Vec<u32> = lines
+            .iter()
+            .map(|line| u32::from_str_radix(line, 2).unwrap())
+            .collect();
+
+        let oxygen = find_rating(numbers.clone(), bit_len, most_common_bit);
+        let co2_rating = find_rating(numbers, bit_len, least_common_bit);
+
+        oxygen * co2_rating
+    }
+}
+
+/// The numbers are read as `u32`s.
+const MAX_BITS: usize = 32;
+
+/// Parses a row of bits, which has to be as wide as the first row if there is one.
+fn parse_row(line: &str, width: Option<usize>) -> Result<String, ParseError> {
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| *c != '0' && *c != '1') {
+        return Err(ParseError::new(
+            line,
+            &line[i..i + c.len_utf8()],
+            "expected '0' or '1'",
+        ));
+    }
+
+    // Only '0' and '1' are left, so every char is a single byte.
+    match width {
+        Some(w) if line.len() > w => Err(ParseError::new(
+            line,
+            &line[w..],
+            format!("expected a row of {} bits", w),
+        )),
+        Some(w) if line.len() < w => Err(ParseError::end_of_line(
+            line,
+            format!("expected a row of {} bits", w),
+        )),
+        None if line.is_empty() => Err(ParseError::end_of_line(line, "expected '0' or '1'")),
+        None if line.len() > MAX_BITS => Err(ParseError::new(
+            line,
+            &line[MAX_BITS..],
+            format!("expected at most {} bits", MAX_BITS),
+        )),
+        _ => Ok(line.to_string()),
+    }
+}
+
+pub fn gamma_epsilon(lines: &[String]) -> (u32, u32) {
+    let rows: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+
+    let colums: Vec<Vec<char>> = (0..rows[0].len())
+        .map(|i| rows.iter().map(|inner| inner[i]).collect())
+        .collect();
+
+    let gamma_str: String = colums
+        .iter()
+        .map(|d| {
+            if d.iter().filter(|x| **x == '1').count() >= d.iter().filter(|x| **x == '0').count() {
+                '1'
+            } else {
+                '0'
+            }
+        })
+        .collect();
+
+    let bit_len = gamma_str.len();
+
+    let gamma = u32::from_str_radix(&gamma_str, 2).unwrap();
+    let mask: u32 = (1 << bit_len) - 1;
+
+    let epsilon = !gamma & mask;
+
+    (gamma, epsilon)
+}
+
+/// Filters `numbers` bit by bit (starting with the most significant one) until only a single
+/// number is left.
+fn find_rating<F>(mut numbers: Vec<u32>, bit_len: usize, bit_criteria: F) -> u32
+where
+    F: Fn(&[u32], usize) -> u32,
+{
+    for i in (0..bit_len).rev() {
+        let mask = 1 << i;
+        let b = bit_criteria(&numbers, i);
+
+        numbers = numbers
+            .iter()
+            .filter(|n| *n & mask == b << i)
+            .copied()
+            .collect();
+
+        if numbers.len() == 1 {
+            break;
+        }
+    }
+
+    *numbers.first().unwrap()
+}
+
+fn most_common_bit(numbers: &[u32], index: usize) -> u32 {
+    let mask = 1 << index;
+    let ones = numbers.iter().filter(|n| (*n & mask) > 0).count();
+
+    if ones >= numbers.len() - ones {
+        1
+    } else {
+        0
+    }
+}
+
+fn least_common_bit(numbers: &[u32], index: usize) -> u32 {
+    match most_common_bit(numbers, index) {
+        0 => 1,
+        1 => 0,
+        _ => panic!("nope"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_common_bit() {
+        assert_eq!(most_common_bit(&[0, 1, 1], 0), 1);
+        assert_eq!(most_common_bit(&[0, 1], 0), 1);
+        assert_eq!(most_common_bit(&[0, 1, 0], 0), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| {
+            let e = Day03::parse(input).unwrap_err();
+            (e.line, e.column, e.message)
+        };
+
+        assert_eq!(Day03::parse("101\n011").unwrap(), ["101", "011"]);
+        assert_eq!(error("101\n10"), (2, 3, "expected a row of 3 bits".into()));
+        assert_eq!(
+            error("101\n1011"),
+            (2, 4, "expected a row of 3 bits".into())
+        );
+        assert_eq!(error("10x"), (1, 3, "expected '0' or '1'".into()));
+        assert_eq!(
+            error(&"1".repeat(33)),
+            (1, 33, "expected at most 32 bits".into())
+        );
+        assert_eq!(error("\n1"), (1, 1, "expected '0' or '1'".into()));
+        assert_eq!(error(""), (1, 1, "expected at least one row".into()));
+    }
+}
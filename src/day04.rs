@@ -1,9 +1,15 @@
 use itertools::Itertools;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
-#[derive(Debug, Eq, PartialEq)]
-struct Bingo {
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day04;
+
+const BOARD_SIZE: usize = 5;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Bingo {
     field: Vec<Vec<u32>>,
     marks: HashSet<(usize, usize)>,
 }
@@ -66,8 +72,8 @@ impl Bingo {
     }
 }
 
-#[derive(Debug)]
-struct Game {
+#[derive(Debug, Clone)]
+pub struct Game {
     inputs: Vec<u32>,
     bingos: Vec<Bingo>,
 }
@@ -86,71 +92,90 @@ impl Game {
     }
 }
 
-fn main() {
-    let input = read_to_string("inputs/day04.txt").expect("file not found");
+impl Solution for Day04 {
+    type Input = Game;
+    type Output1 = u32;
+    type Output2 = u32;
 
-    let mut game = parse_bingos(&input);
-
-    let mut result = 0;
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_bingos(input)
+    }
 
-    let inputs = game.inputs.clone();
+    fn part_1(game: &Self::Input) -> u32 {
+        let mut game = game.clone();
 
-    // Part 1
-    for n in &inputs {
-        if let Some(b) = game.mark(*n) {
-            result = b.sum_of_unmarked() * n;
-            break;
+        for n in game.inputs.clone() {
+            if let Some(b) = game.mark(n) {
+                return b.sum_of_unmarked() * n;
+            }
         }
-    }
 
-    println!("Part 1: {}", result);
+        0
+    }
 
-    let mut game = parse_bingos(&input);
+    fn part_2(game: &Self::Input) -> u32 {
+        let mut game = game.clone();
 
-    let mut result: u32 = 0;
+        for n in game.inputs.clone() {
+            game.mark(n);
 
-    for n in inputs {
-        game.mark(n);
+            if game.bingos.len() == 1 && game.bingos[0].is_bingo() {
+                return game.bingos[0].sum_of_unmarked() * n;
+            }
 
-        if game.bingos.len() == 1 && game.bingos[0].is_bingo() {
-            result = game.bingos[0].sum_of_unmarked() * n;
-            break;
+            game.drop_bingos();
         }
 
-        game.drop_bingos();
+        0
     }
-
-    println!("Part 2: {}", result);
 }
 
-fn parse_bingos(input: &str) -> Game {
-    let mut lines = input.lines();
+pub fn parse_bingos(input: &str) -> Result<Game, ParseError> {
+    let mut lines = input.lines().enumerate();
 
-    let numbers: Vec<u32> = lines
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|n| str::parse(n).unwrap())
-        .collect();
+    let numbers: Vec<u32> = match lines.next() {
+        Some((_, line)) => parse::numbers(line)?,
+        None => return Err(ParseError::end_of_line("", "expected drawn numbers")),
+    };
 
-    lines.next().unwrap();
-
-    let bingos: Vec<Bingo> = lines
-        .filter(|l| !l.is_empty())
-        .map(|l| {
-            l.split_ascii_whitespace()
-                .map(|n| str::parse::<u32>(n).unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>()
-        .chunks(5)
+    let mut rows: Vec<Vec<u32>> = vec![];
+    let mut last_line = 1;
+
+    for (i, line) in lines.filter(|(_, l)| !l.is_empty()) {
+        last_line = i + 1;
+
+        let row = line
+            .split_ascii_whitespace()
+            .map(|n| parse::number(line, n))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.on_line(last_line))?;
+
+        if row.len() != BOARD_SIZE {
+            return Err(
+                ParseError::new(line, line, format!("expected {} numbers", BOARD_SIZE))
+                    .on_line(last_line),
+            );
+        }
+
+        rows.push(row);
+    }
+
+    if !rows.len().is_multiple_of(BOARD_SIZE) {
+        return Err(
+            ParseError::end_of_line("", format!("expected {} rows per board", BOARD_SIZE))
+                .on_line(last_line + 1),
+        );
+    }
+
+    let bingos: Vec<Bingo> = rows
+        .chunks(BOARD_SIZE)
         .map(|x| Bingo::new(x.to_owned()))
         .collect();
 
-    Game {
+    Ok(Game {
         inputs: numbers,
         bingos,
-    }
+    })
 }
 
 #[cfg(test)]
@@ -161,25 +186,25 @@ mod tests {
     fn bingo_horizontal() {
         let mut b = Bingo::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
 
-        assert_eq!(b.is_bingo(), false);
+        assert!(!b.is_bingo());
         b.mark(1);
         b.mark(2);
-        assert_eq!(b.is_bingo(), false);
+        assert!(!b.is_bingo());
         b.mark(3);
-        assert_eq!(b.is_bingo(), true);
+        assert!(b.is_bingo());
     }
 
     #[test]
     fn bingo_vertical() {
         let mut b = Bingo::new(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
 
-        assert_eq!(b.is_bingo(), false);
+        assert!(!b.is_bingo());
         b.mark(2);
         b.mark(6);
-        assert_eq!(b.is_bingo(), false);
+        assert!(!b.is_bingo());
         b.mark(8);
-        assert_eq!(b.is_bingo(), false);
+        assert!(!b.is_bingo());
         b.mark(5);
-        assert_eq!(b.is_bingo(), true);
+        assert!(b.is_bingo());
     }
 }
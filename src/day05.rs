@@ -1,26 +1,26 @@
 use std::collections::HashMap;
-use std::fs::read_to_string;
-
-fn parse_coord(coord: &str) -> (i32, i32) {
-    let mut cs = coord.split(',');
-    (
-        str::parse(cs.next().unwrap()).unwrap(),
-        str::parse(cs.next().unwrap()).unwrap(),
-    )
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day05;
+
+pub fn parse_coord(line: &str, coord: &str) -> Result<(i32, i32), ParseError> {
+    let (x, y) = parse::split_once(line, coord, ",")?;
+
+    Ok((parse::number(line, x)?, parse::number(line, y)?))
 }
 
-fn parse_line(line: &str) -> Line {
-    let mut parts = line.split(" -> ");
-    let from = parse_coord(parts.next().unwrap());
-    let to = parse_coord(parts.next().unwrap());
+pub fn parse_line(line: &str) -> Result<Line, ParseError> {
+    let (from, to) = parse::split_once(line, line, " -> ")?;
 
-    Line(from..=to)
+    Ok(Line(parse_coord(line, from)?..=parse_coord(line, to)?))
 }
 
 #[derive(Debug)]
-struct Line(std::ops::RangeInclusive<(i32, i32)>);
+pub struct Line(std::ops::RangeInclusive<(i32, i32)>);
 
-struct LineIter {
+pub struct LineIter {
     dir: (i32, i32),
     start: (i32, i32),
     current_step: u32,
@@ -47,17 +47,17 @@ impl Iterator for LineIter {
 }
 
 impl Line {
-    fn is_horizontal(&self) -> bool {
+    pub fn is_horizontal(&self) -> bool {
         self.0.start().1 == self.0.end().1
     }
 
-    fn is_vertical(&self) -> bool {
+    pub fn is_vertical(&self) -> bool {
         self.0.start().0 == self.0.end().0
     }
 
-    fn iter(&self) -> LineIter {
-        let diff_x = self.0.end().0 as i32 - self.0.start().0 as i32;
-        let diff_y = self.0.end().1 as i32 - self.0.start().1 as i32;
+    pub fn iter(&self) -> LineIter {
+        let diff_x = self.0.end().0 - self.0.start().0;
+        let diff_y = self.0.end().1 - self.0.start().1;
 
         let dir_x = diff_x.signum();
         let dir_y = diff_y.signum();
@@ -71,36 +71,40 @@ impl Line {
     }
 }
 
-fn main() {
-    let input = read_to_string("inputs/day05.txt").expect("file not found");
-
-    let lines: Vec<Line> = input.lines().map(|l| parse_line(l)).collect();
-
-    let part_1_lines: Vec<_> = lines
-        .iter()
-        .filter(|r| r.is_horizontal() || r.is_vertical())
-        .collect();
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let mut cover: HashMap<(i32, i32), u32> = HashMap::new();
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, parse_line)
+    }
 
-    for p in part_1_lines.iter().flat_map(|r| r.iter()) {
-        let entry = cover.entry(p).or_insert(0);
-        *entry += 1;
+    fn part_1(lines: &Self::Input) -> usize {
+        count_overlaps(
+            lines
+                .iter()
+                .filter(|r| r.is_horizontal() || r.is_vertical()),
+        )
     }
 
-    println!(
-        "Part 1 (without diagonal): {}",
-        cover.iter().filter(|(_, v)| **v > 1).count()
-    );
+    fn part_2(lines: &Self::Input) -> usize {
+        count_overlaps(lines.iter())
+    }
+}
 
+fn count_overlaps<'a, I>(lines: I) -> usize
+where
+    I: Iterator<Item = &'a Line>,
+{
     let mut cover: HashMap<(i32, i32), u32> = HashMap::new();
 
-    for p in lines.iter().flat_map(|r| r.iter()) {
+    for p in lines.flat_map(|r| r.iter()) {
         let entry = cover.entry(p).or_insert(0);
         *entry += 1;
     }
 
-    println!("Part 2: {}", cover.iter().filter(|(_, v)| **v > 1).count());
+    cover.iter().filter(|(_, v)| **v > 1).count()
 }
 
 #[cfg(test)]
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<i128>;
+    type Output1 = i128;
+    type Output2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::numbers(input.lines().next().unwrap_or(""))
+    }
+
+    fn part_1(fish: &Self::Input) -> i128 {
+        let mut cache: HashMap<i128, i128> = HashMap::new();
+
+        fish.iter().map(|f| lanternfish(80 - *f, &mut cache)).sum()
+    }
+
+    fn part_2(fish: &Self::Input) -> i128 {
+        let mut cache: HashMap<i128, i128> = HashMap::new();
+
+        fish.iter().map(|f| lanternfish(256 - *f, &mut cache)).sum()
+    }
+}
+
+pub fn lanternfish(remaining_days: i128, cache: &mut HashMap<i128, i128>) -> i128 {
+    if let Some(n) = cache.get(&remaining_days) {
+        return *n;
+    }
+
+    if remaining_days <= 0 {
+        return 1;
+    }
+
+    // Only the day of reproduction is interesting, so we skip these "boring" days of mere
+    // existence..
+    let r = lanternfish(remaining_days - 7, cache) + lanternfish(remaining_days - 9, cache);
+    cache.insert(remaining_days, r);
+    r
+}
@@ -1,21 +1,27 @@
-use std::fs::read_to_string;
-fn main() {
-    let input = read_to_string("inputs/day07.txt").expect("file not found");
+use crate::parse::{self, ParseError};
+use crate::Solution;
 
-    let values: Vec<i64> = input
-        .lines()
-        .nth(0)
-        .unwrap()
-        .split(',')
-        .map(|l| l.parse::<i64>().expect("not a number"))
-        .collect();
+pub struct Day07;
 
-    println!("Part 1: {}", min_fuel(&values, fuel_cost_part_1).unwrap());
+impl Solution for Day07 {
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    println!("Part 2: {}", min_fuel(&values, fuel_cost_part_2).unwrap());
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::numbers(input.lines().next().unwrap_or(""))
+    }
+
+    fn part_1(positions: &Self::Input) -> i64 {
+        min_fuel(positions, fuel_cost_part_1).unwrap()
+    }
+
+    fn part_2(positions: &Self::Input) -> i64 {
+        min_fuel(positions, fuel_cost_part_2).unwrap()
+    }
 }
 
-fn min_fuel<F>(positions: &[i64], fuel_fn: F) -> Option<i64>
+pub fn min_fuel<F>(positions: &[i64], fuel_fn: F) -> Option<i64>
 where
     F: Fn(i64) -> i64,
 {
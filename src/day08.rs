@@ -0,0 +1,194 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day08;
+
+pub type Entry = (Vec<HashSet<char>>, Vec<String>);
+
+pub fn parse_line(line: &str) -> Result<Entry, ParseError> {
+    let (first, last) = parse::split_once(line, line, " | ")?;
+
+    let first = parse_patterns(line, first, 10)?
+        .iter()
+        .map(|w| w.chars().collect::<HashSet<_>>())
+        .collect();
+    let last = parse_patterns(line, last, 4)?
+        .iter()
+        .map(|w| {
+            let mut chars = w.chars().collect::<Vec<_>>();
+            chars.sort();
+            chars.iter().collect::<String>()
+        })
+        .collect();
+
+    Ok((first, last))
+}
+
+fn parse_patterns<'a>(line: &str, part: &'a str, count: usize) -> Result<Vec<&'a str>, ParseError> {
+    let words: Vec<&str> = part.split_whitespace().collect();
+
+    for w in &words {
+        if let Some((i, c)) = w.char_indices().find(|(_, c)| !('a'..='g').contains(c)) {
+            return Err(ParseError::new(
+                line,
+                &w[i..i + c.len_utf8()],
+                "expected a segment between 'a' and 'g'",
+            ));
+        }
+    }
+
+    if words.len() != count {
+        return Err(ParseError::new(
+            line,
+            part,
+            format!("expected {} patterns", count),
+        ));
+    }
+
+    Ok(words)
+}
+
+impl Solution for Day08 {
+    type Input = Vec<Entry>;
+    type Output1 = usize;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, parse_line)
+    }
+
+    fn part_1(entries: &Self::Input) -> usize {
+        entries
+            .iter()
+            .flat_map(|(_, l)| l.iter())
+            .filter(|d| [2_usize, 4, 3, 7].contains(&d.len()))
+            .count()
+    }
+
+    fn part_2(entries: &Self::Input) -> i32 {
+        entries
+            .iter()
+            .map(|(digits, number)| decode_entry(digits, number))
+            .sum()
+    }
+}
+
+/// Deduces the wiring from the ten unique signal patterns and decodes the four digit output value.
+pub fn decode_entry(digits: &[HashSet<char>], number: &[String]) -> i32 {
+    /*
+     *   aaaa
+     *  b    c
+     *  b    c
+     *   dddd
+     *  e    f
+     *  e    f
+     *   gggg
+     */
+
+    // Digits with unique length:
+    let one = digits.iter().find(|d| d.len() == 2).unwrap();
+    let four = digits.iter().find(|d| d.len() == 4).unwrap();
+    let seven = digits.iter().find(|d| d.len() == 3).unwrap();
+    let eight = digits.iter().find(|d| d.len() == 7).unwrap();
+
+    // Finding the remaining digits/elements using set operations:
+
+    // {a} = 7 \ 1
+    let a = difference(seven, one);
+
+    // {eg} = 8 \ {a} \ 4
+    let mut eg = difference(eight, &a);
+    eg = difference(&eg, four);
+
+    // Removing {eg} from all digits leaves 3 and 7 with a length of 3.
+    let two = digits
+        .iter()
+        .find(|d| difference(d, &eg).len() == 3 && **d != *seven)
+        .unwrap();
+
+    // {bf} = 8 \ 2
+    let bf = difference(eight, two);
+    // {c} = 1 \ {bf}
+    let c = difference(one, &bf);
+    // {f} = 1 \ {c}
+    let f = difference(one, &c);
+    // {d} = 2 \ {eg} \ {a} \ {c}
+    let d = difference(&difference(&difference(two, &eg), &a), &c);
+
+    // 0 = 8 \ {d}
+    let zero = difference(eight, &d);
+
+    // 6 = 8 \ {c}
+    let six = difference(eight, &c);
+
+    // 8 \ 2 \ {f}
+    let b = difference(&difference(eight, two), &f);
+
+    // The difference between 3 and 9 is b. we can find 9 and 3 by simple brute force.
+    let (three, nine) = digits
+        .iter()
+        .cartesian_product(digits.iter())
+        .find(|(pot_three, pot_nine)| pot_nine.len() == 6 && difference(pot_nine, pot_three) == b)
+        .unwrap();
+
+    // 5 = (3 \ {c}) + {b}
+    let five = union(&difference(three, &c), &b);
+
+    let map: HashMap<String, i32> = [
+        (zero.iter().sorted().collect(), 0),
+        (one.iter().sorted().collect(), 1),
+        (two.iter().sorted().collect(), 2),
+        (three.iter().sorted().collect(), 3),
+        (four.iter().sorted().collect(), 4),
+        (five.iter().sorted().collect(), 5),
+        (six.iter().sorted().collect(), 6),
+        (seven.iter().sorted().collect(), 7),
+        (eight.iter().sorted().collect(), 8),
+        (nine.iter().sorted().collect(), 9),
+    ]
+    .into_iter()
+    .collect();
+
+    to_digits(&map, number)
+}
+
+fn to_digits(map: &HashMap<String, i32>, s: &[String]) -> i32 {
+    s.iter().rev().enumerate().fold(0, |sum, (i, d)| {
+        let n = *map.get(d).unwrap();
+        sum + n * 10_i32.pow(i as u32)
+    })
+}
+
+fn difference(a: &HashSet<char>, b: &HashSet<char>) -> HashSet<char> {
+    a.difference(b).copied().collect()
+}
+
+fn union(a: &HashSet<char>, b: &HashSet<char>) -> HashSet<char> {
+    a.union(b).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_digits() {
+        let map = [
+            ("abc".to_string(), 0),
+            ("gfr".to_string(), 2),
+            ("ita".to_string(), 8),
+        ]
+        .into_iter()
+        .collect();
+
+        let result = to_digits(
+            &map,
+            &["ita".to_string(), "gfr".to_string(), "abc".to_string()],
+        );
+        assert_eq!(result, 820);
+    }
+}
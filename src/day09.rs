@@ -0,0 +1,97 @@
+use std::collections::{HashSet, VecDeque};
+
+use itertools::Itertools;
+
+use crate::grid::{Grid, Pos};
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day09;
+
+pub fn parse_grid(input: &str) -> Result<Grid<u32>, ParseError> {
+    let grid = Grid::parse(input, "a digit", |c| c.to_digit(10))?;
+
+    if grid.width() == 0 {
+        return Err(ParseError::end_of_line("", "expected at least one digit"));
+    }
+
+    Ok(grid)
+}
+
+impl Solution for Day09 {
+    type Input = Grid<u32>;
+    type Output1 = u32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part_1(grid: &Self::Input) -> u32 {
+        local_minima(grid)
+            .iter()
+            .fold(0_u32, |sum, (_, h)| sum + h + 1)
+    }
+
+    fn part_2(grid: &Self::Input) -> usize {
+        // Part 2 is a flood fill (breadth first search using queue) until we find a 9.
+        let basins: Vec<usize> = local_minima(grid)
+            .iter()
+            .map(|(pos, _)| basin_size(grid, *pos))
+            .sorted()
+            .rev()
+            .collect();
+
+        basins[0] * basins[1] * basins[2]
+    }
+}
+
+pub fn local_minima(grid: &Grid<u32>) -> Vec<(Pos, u32)> {
+    grid.iter()
+        .filter(|(pos, v)| grid.neighbours_4(*pos).all(|n| **v < grid[n]))
+        .map(|(pos, v)| (pos, *v))
+        .collect()
+}
+
+pub struct FloodFill<'a> {
+    visited: HashSet<Pos>,
+    queue: VecDeque<Pos>,
+    grid: &'a Grid<u32>,
+}
+
+impl<'a> Iterator for FloodFill<'a> {
+    type Item = (Pos, u32);
+
+    fn next(&mut self) -> Option<<Self as Iterator>::Item> {
+        if let Some(c) = self.queue.pop_front() {
+            for n in self.grid.neighbours_4(c) {
+                if !self.visited.contains(&n) && self.grid[n] != 9 {
+                    self.visited.insert(n);
+                    self.queue.push_back(n);
+                }
+            }
+
+            Some((c, self.grid[c]))
+        } else {
+            None
+        }
+    }
+}
+
+pub fn flood_fill_iter(grid: &Grid<u32>, start: Pos) -> FloodFill<'_> {
+    let mut visited: HashSet<Pos> = HashSet::new();
+    let mut queue: VecDeque<Pos> = VecDeque::new();
+
+    queue.push_back(start);
+    visited.insert(start);
+
+    FloodFill {
+        grid,
+        queue,
+        visited,
+    }
+}
+
+pub fn basin_size(grid: &Grid<u32>, start: Pos) -> usize {
+    flood_fill_iter(grid, start).count()
+}
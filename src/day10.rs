@@ -0,0 +1,109 @@
+use crate::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Result<(), ParseFail>>;
+    type Output1 = i64;
+    type Output2 = Answer<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse::parse_lines(input, |l| {
+            match l.char_indices().find(|(_, c)| !"()[]{}<>".contains(*c)) {
+                Some((i, c)) => Err(ParseError::new(
+                    l,
+                    &l[i..i + c.len_utf8()],
+                    "expected a bracket",
+                )),
+                None => Ok(check_line(l)),
+            }
+        })?;
+
+        if lines.is_empty() {
+            return Err(ParseError::end_of_input(
+                input,
+                "expected at least one line",
+            ));
+        }
+
+        Ok(lines)
+    }
+
+    fn part_1(checked_lines: &Self::Input) -> i64 {
+        let mut sum = 0;
+        for l in checked_lines {
+            if let Err(ParseFail::Corrupted(c)) = l {
+                let p = match c {
+                    ')' => 3,
+                    ']' => 57,
+                    '}' => 1197,
+                    '>' => 25137,
+                    _ => panic!("invalid char"),
+                };
+                sum += p;
+            }
+        }
+
+        sum
+    }
+
+    fn part_2(checked_lines: &Self::Input) -> Answer<i64> {
+        let mut scores: Vec<i64> = checked_lines
+            .iter()
+            .filter_map(|l| match l {
+                Err(ParseFail::Incomplete(s)) => Some(s),
+                _ => None,
+            })
+            .map(|stack| {
+                stack.iter().rev().fold(0, |sum, c| {
+                    let p = match c {
+                        '(' => 1,
+                        '[' => 2,
+                        '{' => 3,
+                        '<' => 4,
+                        _ => panic!("invalid char"),
+                    };
+                    sum * 5 + p
+                })
+            })
+            .collect();
+
+        if scores.is_empty() {
+            return Answer::None("no line is incomplete".to_string());
+        }
+
+        scores.sort();
+
+        Answer::Found(scores[scores.len() / 2])
+    }
+}
+
+pub enum ParseFail {
+    Corrupted(char),
+    Incomplete(Vec<char>),
+}
+
+pub fn check_line(line: &str) -> Result<(), ParseFail> {
+    let mut stack = Vec::new();
+
+    for c in line.chars() {
+        match c {
+            '{' | '(' | '<' | '[' => stack.push(c),
+            '}' | ')' | '>' | ']' => match stack.pop() {
+                Some('(') if c == ')' => {}
+                Some('[') if c == ']' => {}
+                Some('{') if c == '}' => {}
+                Some('<') if c == '>' => {}
+                _ => return Err(ParseFail::Corrupted(c)),
+            },
+            _ => panic!("invalid char"),
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(ParseFail::Incomplete(stack));
+    }
+
+    Ok(())
+}
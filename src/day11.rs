@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<u8>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    fn part_1(numbers: &Self::Input) -> usize {
+        let mut values = numbers.clone();
+
+        let mut flashes = 0;
+
+        for _ in 1..=100 {
+            step(&mut values);
+            flashes += flash(&mut values);
+        }
+
+        flashes
+    }
+
+    fn part_2(numbers: &Self::Input) -> usize {
+        let mut values = numbers.clone();
+
+        let mut steps = 0;
+        loop {
+            step(&mut values);
+            steps += 1;
+            let flashes = flash(&mut values);
+            if flashes == values.width() * values.height() {
+                return steps;
+            }
+        }
+    }
+}
+
+pub fn step(grid: &mut Grid<u8>) {
+    for cell in grid.values_mut() {
+        *cell += 1;
+    }
+}
+
+pub fn flash(grid: &mut Grid<u8>) -> usize {
+    let mut queue = VecDeque::new();
+    let mut flashed = HashSet::new();
+
+    for (pos, cell) in grid.iter() {
+        if *cell > 9 {
+            queue.push_back(pos);
+            flashed.insert(pos);
+        }
+    }
+
+    while let Some(c) = queue.pop_front() {
+        for n in grid.neighbours_8(c).collect::<Vec<_>>() {
+            grid[n] += 1;
+            if grid[n] > 9 && !flashed.contains(&n) {
+                queue.push_back(n);
+                flashed.insert(n);
+            }
+        }
+    }
+
+    for c in &flashed {
+        grid[*c] = 0;
+    }
+    flashed.len()
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day12;
+
+pub type Connections = HashMap<String, Vec<String>>;
+
+impl Solution for Day12 {
+    type Input = Connections;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut connections: Connections = HashMap::new();
+
+        for (a, b) in parse::parse_lines(input, parse_line)? {
+            let entry = connections.entry(a.to_string()).or_default();
+
+            entry.push(b.to_string());
+
+            let entry = connections.entry(b.to_string()).or_default();
+
+            entry.push(a.to_string());
+        }
+
+        if !connections.contains_key("start") {
+            return Err(ParseError::end_of_input(
+                input,
+                "expected a connection of the start cave",
+            ));
+        }
+
+        Ok(connections)
+    }
+
+    fn part_1(connections: &Self::Input) -> usize {
+        let mut visited_small_caves = HashMap::new();
+        visited_small_caves.insert("start", 1);
+
+        all_paths(
+            &["start"],
+            connections,
+            visited_small_caves,
+            &may_visit_part_1,
+        )
+        .len()
+    }
+
+    fn part_2(connections: &Self::Input) -> usize {
+        let mut visited_small_caves = HashMap::new();
+        visited_small_caves.insert("start", 1);
+
+        all_paths(
+            &["start"],
+            connections,
+            visited_small_caves,
+            &may_visit_part_2,
+        )
+        .len()
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<(&str, &str), ParseError> {
+    parse::split_once(line, line, "-")
+}
+
+pub fn all_paths<'a, F>(
+    start: &[&'a str],
+    connections: &'a Connections,
+    visited_small_caves: HashMap<&'a str, usize>,
+    f: &F,
+) -> Vec<Vec<&'a str>>
+where
+    F: Fn(&str, &HashMap<&str, usize>) -> bool,
+{
+    if start.last().unwrap() == &"end" {
+        return vec![start.to_owned()];
+    }
+
+    let mut result: Vec<Vec<&'a str>> = vec![];
+
+    for n in connections.get(*start.last().unwrap()).unwrap() {
+        let mut visited_small_caves = visited_small_caves.clone();
+
+        if !f(n, &visited_small_caves) {
+            continue;
+        }
+
+        if n.chars().all(|c| c.is_ascii_lowercase()) {
+            let entry = visited_small_caves.entry(n).or_insert(0);
+            *entry += 1;
+        }
+
+        let mut new_start = start.to_owned();
+        new_start.push(n);
+
+        let mut paths = all_paths(&new_start, connections, visited_small_caves.clone(), f);
+        result.append(&mut paths);
+    }
+
+    result
+}
+
+fn may_visit_part_1(node: &str, visited_small_caves: &HashMap<&str, usize>) -> bool {
+    let visit_count = visited_small_caves.get(node);
+
+    !matches!(visit_count, Some(n) if *n > 0)
+}
+
+fn may_visit_part_2(node: &str, visited_small_caves: &HashMap<&str, usize>) -> bool {
+    let visit_count = visited_small_caves.get(node);
+
+    match visit_count {
+        Some(2) => false,
+        Some(1) if node == "start" || node == "end" => false,
+        Some(1) if visited_small_caves.values().any(|c| *c >= 2) => false,
+        Some(_) => true,
+        None => true,
+    }
+}
@@ -0,0 +1,119 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day13;
+
+pub type Dot = (u32, u32);
+pub type Fold = (char, u32);
+
+impl Solution for Day13 {
+    type Input = (Vec<Dot>, Vec<Fold>);
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1((coordinates, folds): &Self::Input) -> usize {
+        let dots = coordinates.iter().copied().collect::<HashSet<_>>();
+
+        fold(&dots, folds[0]).len()
+    }
+
+    fn part_2((coordinates, folds): &Self::Input) -> String {
+        let mut dots = coordinates.iter().copied().collect::<HashSet<_>>();
+
+        for f in folds {
+            dots = fold(&dots, *f);
+        }
+
+        // Start the code on a fresh line so the letters line up.
+        format!("\n{}", render_paper(&dots))
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Vec<Dot>, Vec<Fold>), ParseError> {
+    let mut lines = input.lines().enumerate();
+    let mut coordinates = vec![];
+    let mut folds = vec![];
+
+    for (i, line) in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        coordinates.push(parse_dot(line).map_err(|e| e.on_line(i + 1))?);
+    }
+
+    for (i, line) in lines {
+        folds.push(parse_fold(line).map_err(|e| e.on_line(i + 1))?);
+    }
+
+    if folds.is_empty() {
+        return Err(ParseError::end_of_input(
+            input,
+            "expected a fold instruction",
+        ));
+    }
+
+    Ok((coordinates, folds))
+}
+
+fn parse_dot(line: &str) -> Result<Dot, ParseError> {
+    let (x, y) = parse::split_once(line, line, ",")?;
+
+    Ok((parse::number(line, x)?, parse::number(line, y)?))
+}
+
+/// Parses a line like `fold along y=7`.
+fn parse_fold(line: &str) -> Result<Fold, ParseError> {
+    let rest = parse::strip_prefix(line, line, "fold along ")?;
+    let (dir, value) = parse::split_once(line, rest, "=")?;
+
+    let dir = match dir {
+        "x" => 'x',
+        "y" => 'y',
+        _ => return Err(ParseError::new(line, dir, "expected 'x' or 'y'")),
+    };
+
+    Ok((dir, parse::number(line, value)?))
+}
+
+pub fn fold(dots: &HashSet<Dot>, (dir, f): Fold) -> HashSet<Dot> {
+    let (before_fold, after_fold) = if dir == 'x' {
+        let (mut after_fold, before_fold): (HashSet<Dot>, HashSet<Dot>) =
+            dots.iter().partition(|(x, _y)| *x > f);
+
+        after_fold = after_fold.iter().map(|(x, y)| (f - (x - f), *y)).collect();
+
+        (before_fold, after_fold)
+    } else {
+        let (mut after_fold, before_fold): (HashSet<Dot>, HashSet<Dot>) =
+            dots.iter().partition(|(_x, y)| *y > f);
+
+        after_fold = after_fold.iter().map(|(x, y)| (*x, f - (y - f))).collect();
+
+        (before_fold, after_fold)
+    };
+
+    before_fold
+        .union(&after_fold)
+        .copied()
+        .collect::<HashSet<_>>()
+}
+
+pub fn render_paper(set: &HashSet<Dot>) -> String {
+    let max_y = *set.iter().map(|(_x, y)| y).max().unwrap();
+    let max_x = *set.iter().map(|(x, _y)| x).max().unwrap();
+
+    (0..=max_y)
+        .map(|y| {
+            (0..=max_x)
+                .map(|x| if set.contains(&(x, y)) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .join("\n")
+}
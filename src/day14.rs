@@ -0,0 +1,178 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day14;
+
+pub type Insertions = HashMap<(u8, u8), u8>;
+
+impl Solution for Day14 {
+    type Input = (Vec<u8>, Insertions);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1((template, insertions): &Self::Input) -> usize {
+        run(template.clone(), insertions, 10)
+    }
+
+    fn part_2((template, insertions): &Self::Input) -> usize {
+        run_2(template, insertions, 40)
+    }
+}
+
+fn parse_insertion(line: &str) -> Result<((u8, u8), u8), ParseError> {
+    let (from, to) = parse::split_once(line, line, " -> ")?;
+
+    let from = from
+        .bytes()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::new(line, from, "expected a pair of elements"))?;
+    let to = match to.as_bytes() {
+        [to] => *to,
+        _ => return Err(ParseError::new(line, to, "expected a single element")),
+    };
+
+    Ok((from, to))
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<u8>, Insertions), ParseError> {
+    let mut lines = input.lines().enumerate();
+
+    let template_line = match lines.next() {
+        Some((_, line)) if !line.is_empty() => line,
+        _ => return Err(ParseError::end_of_line("", "expected a polymer template")),
+    };
+    let template: Vec<u8> = template_line.bytes().collect();
+
+    // The line of each rule, to point at the rule which creates a pair without one.
+    let mut rule_lines = HashMap::new();
+    let mut insertions = Insertions::new();
+
+    for (i, line) in lines.filter(|(_, l)| !l.is_empty()) {
+        let (from, to) = parse_insertion(line).map_err(|e| e.on_line(i + 1))?;
+        rule_lines.insert(from, (i, line));
+        insertions.insert(from, to);
+    }
+
+    for (i, pair) in template.windows(2).enumerate() {
+        if !insertions.contains_key(&(pair[0], pair[1])) {
+            let token = template_line.get(i..i + 2).unwrap_or(template_line);
+            return Err(ParseError::new(
+                template_line,
+                token,
+                "expected a pair with an insertion rule",
+            ));
+        }
+    }
+
+    // Every pair the template grows into needs a rule as well.
+    let mut todo: Vec<(u8, u8)> = template.windows(2).map(|p| (p[0], p[1])).collect();
+    let mut seen: HashSet<(u8, u8)> = todo.iter().copied().collect();
+
+    while let Some(pair) = todo.pop() {
+        let to = insertions[&pair];
+
+        for created in [(pair.0, to), (to, pair.1)] {
+            if !insertions.contains_key(&created) {
+                let (i, line) = rule_lines[&pair];
+                return Err(ParseError::new(
+                    line,
+                    line,
+                    format!(
+                        "expected an insertion rule for {}{}, which this rule creates",
+                        created.0 as char, created.1 as char
+                    ),
+                )
+                .on_line(i + 1));
+            }
+            if seen.insert(created) {
+                todo.push(created);
+            }
+        }
+    }
+
+    Ok((template, insertions))
+}
+
+pub fn run_2(template: &[u8], insertions: &Insertions, count: usize) -> usize {
+    // Contains the count of all (overlapping) pairs. E.g. result['A']['B'] contains how often
+    // `template` contains the substring "AB".
+    let mut result: Vec<Vec<usize>> = vec![vec![0; 256]; 256];
+
+    // Keeps track of how often each character is present in the target string.
+    let mut counts: [usize; 256] = [0; 256];
+
+    for t in template {
+        counts[*t as usize] += 1;
+    }
+
+    for t in template.windows(2) {
+        result[t[0] as usize][t[1] as usize] += 1;
+    }
+
+    for _ in 1..=count {
+        let mut new_result: Vec<Vec<usize>> = result.clone();
+
+        for (from, to) in insertions {
+            if result[from.0 as usize][from.1 as usize] > 0 {
+                // We want to replace AB with ACB, count how often AB occurs in the target string.
+                let occurences = result[from.0 as usize][from.1 as usize];
+
+                // Since we replace all AB, we remove all occurences from the next iteration.
+                new_result[from.0 as usize][from.1 as usize] -= occurences;
+                // Add AC
+                new_result[from.0 as usize][*to as usize] += occurences;
+                // Add CB
+                new_result[*to as usize][from.1 as usize] += occurences;
+                // We produced #occurences new Cs.
+                counts[*to as usize] += occurences;
+            }
+        }
+
+        result = new_result;
+    }
+
+    counts.sort();
+
+    counts[255] - counts.iter().find(|x| **x > 0).unwrap()
+}
+
+pub fn run(mut template: Vec<u8>, insertions: &Insertions, count: usize) -> usize {
+    let mut result: Vec<u8> = Vec::with_capacity(template.len() * 2);
+
+    for _ in 1..=count {
+        result = Vec::with_capacity(template.len() * 2);
+
+        for t in template.windows(2) {
+            let insertion = insertions.get(&(t[0], t[1]));
+
+            result.push(t[0]);
+            result.push(*insertion.unwrap());
+        }
+
+        result.push(*template.last().unwrap());
+
+        template = result.clone();
+    }
+
+    let (min, max) = count_min_max(result);
+    max - min
+}
+
+fn count_min_max(template: Vec<u8>) -> (usize, usize) {
+    let mut map = [0; 256];
+
+    for c in template {
+        map[c as usize] += 1;
+    }
+
+    map.sort();
+
+    (*map.iter().filter(|x| **x > 0).min().unwrap(), map[255])
+}
@@ -0,0 +1,287 @@
+use std::collections::HashSet;
+
+use crate::grid::{self, Grid, Pos};
+use crate::parse::ParseError;
+use crate::search::{self, SearchProblem, SearchResult};
+use crate::Solution;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<u32>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, "a digit from 1 to 9", |c| {
+            c.to_digit(10).filter(|risk| *risk > 0)
+        })?;
+
+        if grid.width() == 0 {
+            return Err(ParseError::end_of_line("", "expected at least one digit"));
+        }
+
+        Ok(grid)
+    }
+
+    fn part_1(grid: &Self::Input) -> usize {
+        lowest_total_risk(grid)
+    }
+
+    fn part_2(grid: &Self::Input) -> usize {
+        lowest_total_risk(&TiledCave::new(grid, 5, 5, 1, wrap_to_digit))
+    }
+}
+
+/// Risk levels of a rectangular cave, entering a position costs its risk level.
+pub trait RiskMap {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    fn risk(&self, pos: Pos) -> u32;
+
+    /// The horizontal and vertical neighbours of `pos` which are inside the cave.
+    fn neighbours(&self, pos: Pos) -> Vec<Pos> {
+        grid::neighbours_4(self.width(), self.height(), pos).collect()
+    }
+}
+
+impl RiskMap for Grid<u32> {
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn risk(&self, pos: Pos) -> u32 {
+        self[pos]
+    }
+}
+
+/// The full cave, made of `tiles_down` x `tiles_across` copies of the scanned `tile`. Each copy's
+/// risk levels are `increment` higher than those of the copy above or to the left of it, passed
+/// through `wrap`. The risk levels are computed when they're needed, see `to_grid` for a copy
+/// with all of them.
+pub struct TiledCave<'a, W> {
+    tile: &'a Grid<u32>,
+    tiles_down: usize,
+    tiles_across: usize,
+    increment: u32,
+    wrap: W,
+}
+
+impl<'a, W> TiledCave<'a, W>
+where
+    W: Fn(u32) -> u32,
+{
+    pub fn new(
+        tile: &'a Grid<u32>,
+        tiles_down: usize,
+        tiles_across: usize,
+        increment: u32,
+        wrap: W,
+    ) -> Self {
+        Self {
+            tile,
+            tiles_down,
+            tiles_across,
+            increment,
+            wrap,
+        }
+    }
+
+    pub fn to_grid(&self) -> Grid<u32> {
+        Grid::from_fn(self.width(), self.height(), |pos| self.risk(pos))
+    }
+}
+
+impl<W> RiskMap for TiledCave<'_, W>
+where
+    W: Fn(u32) -> u32,
+{
+    fn width(&self) -> usize {
+        self.tile.width() * self.tiles_across
+    }
+
+    fn height(&self) -> usize {
+        self.tile.height() * self.tiles_down
+    }
+
+    fn risk(&self, (row, col): Pos) -> u32 {
+        let (height, width) = (self.tile.height(), self.tile.width());
+        let distance = (row / height + col / width) as u32;
+
+        (self.wrap)(self.tile[(row % height, col % width)] + distance * self.increment)
+    }
+}
+
+/// Wraps risk levels above 9 back around to 1, and 0 around to 9.
+pub fn wrap_to_digit(risk: u32) -> u32 {
+    (risk + 8) % 9 + 1
+}
+
+/// Finding the path with the lowest total risk from `start` to `goal`.
+pub struct Cavern<'a, M> {
+    pub map: &'a M,
+    pub start: Pos,
+    pub goal: Pos,
+}
+
+impl<M: RiskMap> SearchProblem for Cavern<'_, M> {
+    type State = Pos;
+
+    fn start(&self) -> Pos {
+        self.start
+    }
+
+    fn successors(&self, pos: &Pos) -> Vec<(Pos, usize)> {
+        self.map
+            .neighbours(*pos)
+            .into_iter()
+            .map(|n| (n, self.map.risk(n) as usize))
+            .collect()
+    }
+
+    fn is_goal(&self, pos: &Pos) -> bool {
+        *pos == self.goal
+    }
+
+    // Every step costs at least 1, see `a_star` for maps where it doesn't.
+    fn heuristic(&self, pos: &Pos) -> usize {
+        manhattan_distance(*pos, self.goal)
+    }
+}
+
+/// Finds the path with the lowest total risk from `start` to `goal`, or `None` if there's no path.
+/// The path includes both `start` and `goal`, the risk of `start` isn't counted.
+///
+/// If any risk level is 0 the heuristic could overestimate, so this falls back to Dijkstra's
+/// algorithm for such maps.
+pub fn a_star<M: RiskMap>(map: &M, start: Pos, goal: Pos) -> Option<SearchResult<Pos>> {
+    let cavern = Cavern { map, start, goal };
+    let any_risk_free = (0..map.height())
+        .flat_map(|row| (0..map.width()).map(move |col| (row, col)))
+        .any(|pos| map.risk(pos) == 0);
+
+    if any_risk_free {
+        search::dijkstra(&cavern)
+    } else {
+        search::a_star(&cavern)
+    }
+}
+
+/// The lowest total risk from the top left to the bottom right corner.
+pub fn lowest_total_risk<M: RiskMap>(map: &M) -> usize {
+    a_star(map, (0, 0), (map.height() - 1, map.width() - 1))
+        .expect("couldn't find goal")
+        .cost
+}
+
+/// Renders the risk levels along `path`, every other position is shown as a `.`.
+pub fn render_path<M: RiskMap>(map: &M, path: &[Pos]) -> String {
+    let on_path: HashSet<Pos> = path.iter().copied().collect();
+
+    Grid::new(map.width(), map.height(), ()).render(|pos, _| {
+        if on_path.contains(&pos) {
+            char::from_digit(map.risk(pos), 10).unwrap_or('?')
+        } else {
+            '.'
+        }
+    })
+}
+
+fn manhattan_distance(from: Pos, to: Pos) -> usize {
+    from.0.abs_diff(to.0) + from.1.abs_diff(to.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn test_render_path() {
+        let grid = Day15::parse(EXAMPLE).unwrap();
+        let result = a_star(&grid, (0, 0), (9, 9)).unwrap();
+
+        assert_eq!(result.cost, 40);
+        assert_eq!(
+            render_path(&grid, &result.path),
+            "1.........
+1.........
+2136511...
+......15..
+.......1..
+.......13.
+........2.
+........3.
+........21
+.........1"
+        );
+    }
+
+    #[test]
+    fn test_tiled_cave() {
+        let grid = Day15::parse(EXAMPLE).unwrap();
+        let cave = TiledCave::new(&grid, 5, 5, 1, wrap_to_digit);
+
+        assert_eq!(
+            cave.to_grid().row(49),
+            Day15::parse("67554889357866599146897761125791887223681299833479")
+                .unwrap()
+                .row(0)
+        );
+        assert_eq!(lowest_total_risk(&cave), 315);
+        assert_eq!(lowest_total_risk(&cave.to_grid()), 315);
+
+        let cave = TiledCave::new(&grid, 50, 30, 2, wrap_to_digit);
+        assert_eq!((cave.width(), cave.height()), (300, 500));
+        assert_eq!(cave.risk((499, 299)), wrap_to_digit(1 + 2 * (49 + 29)));
+    }
+
+    #[test]
+    fn test_risk_free() {
+        assert_eq!(
+            Day15::parse("120\n111").unwrap_err().to_string(),
+            "line 1, column 3: expected a digit from 1 to 9, found '0'"
+        );
+        assert_eq!(wrap_to_digit(0), 9);
+
+        // Straight to the right costs 4, the detour through the risk-free row below only 1. The
+        // Manhattan distance overestimates that, so A* on its own misses the detour.
+        let tile = Day15::parse("12222\n11111").unwrap();
+        let cave = TiledCave::new(&tile, 1, 1, 0, |risk| risk - 1);
+
+        assert_eq!(a_star(&cave, (0, 0), (0, 4)).unwrap().cost, 1);
+        assert_eq!(
+            search::a_star(&Cavern {
+                map: &cave,
+                start: (0, 0),
+                goal: (0, 4)
+            })
+            .unwrap()
+            .cost,
+            4
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let grid = Day15::parse(EXAMPLE).unwrap();
+
+        assert_eq!(a_star(&grid, (0, 0), (10, 10)), None);
+    }
+}
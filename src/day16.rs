@@ -0,0 +1,921 @@
+use std::fmt;
+use std::ops::Range;
+
+use num_bigint::BigUint;
+
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Output1 = usize;
+    type Output2 = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let hex = input.trim_end();
+
+        if let Some((i, c)) = hex.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(ParseError::new(
+                hex,
+                &hex[i..i + c.len_utf8()],
+                "expected a hexadecimal digit",
+            ));
+        }
+
+        decode(&hex_to_bytes(hex)).map_err(|e| {
+            // Point at the hex digit containing the offending bit.
+            let i = (e.offset / 4).min(hex.len());
+            ParseError::new(
+                hex,
+                &hex[i..(i + 1).min(hex.len())],
+                format!("{} at bit {}", e.reason, e.offset),
+            )
+        })
+    }
+
+    fn part_1(packet: &Self::Input) -> usize {
+        packet.version_sum()
+    }
+
+    fn part_2(packet: &Self::Input) -> BigUint {
+        packet.evaluate_big()
+    }
+}
+
+/// Two packets are equal if their contents are, no matter where they were decoded from.
+#[derive(Debug, Clone)]
+pub struct Packet {
+    version: u8,
+    packet_id: u8,
+    kind: Content,
+    /// The bits the packet was decoded from, `None` for packets built in code.
+    span: Option<Range<usize>>,
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        (self.version, self.packet_id, &self.kind) == (other.version, other.packet_id, &other.kind)
+    }
+}
+
+impl Eq for Packet {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Content {
+    Literal(BigUint),
+    Operator(LengthType, Vec<Packet>),
+}
+
+/// How an operator packet announces the size of its sub-packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    /// Length type 0, the total number of bits in 15 bits.
+    Bits,
+    /// Length type 1, the number of sub-packets in 11 bits.
+    Count,
+}
+
+/// Why a transmission couldn't be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    UnexpectedEnd,
+    /// An operator with the wrong number of sub-packets.
+    Arity {
+        packet_id: u8,
+        count: usize,
+    },
+    /// Sub-packets of a length type 0 operator which don't end where announced.
+    LengthMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    NonZeroPadding,
+    /// An operator nested inside `MAX_DEPTH` other operators.
+    TooDeep,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::UnexpectedEnd => write!(f, "unexpected end of transmission"),
+            Reason::Arity { packet_id, count } => {
+                let expected = if *packet_id >= 5 {
+                    "exactly 2"
+                } else {
+                    "at least 1"
+                };
+                write!(
+                    f,
+                    "packet ID {} expects {} sub-packets, found {}",
+                    packet_id, expected, count
+                )
+            }
+            Reason::LengthMismatch { expected, actual } => write!(
+                f,
+                "sub-packets take up {} bits instead of {}",
+                actual, expected
+            ),
+            Reason::NonZeroPadding => write!(f, "non-zero padding after the outermost packet"),
+            Reason::TooDeep => write!(f, "operators nested more than {} deep", MAX_DEPTH),
+        }
+    }
+}
+
+/// An invalid transmission, `offset` is the bit where the problem was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    pub offset: usize,
+    pub reason: Reason,
+}
+
+/// Renders the packet tree as an outline, one packet per line, with sub-packets indented.
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_outline(f, 0)
+    }
+}
+
+fn operator_name(packet_id: u8) -> &'static str {
+    match packet_id {
+        0 => "sum",
+        1 => "product",
+        2 => "min",
+        3 => "max",
+        5 => "gt",
+        6 => "lt",
+        7 => "eq",
+        x => unreachable!("unknown operator packet ID {}", x),
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bit {}: {}", self.offset, self.reason)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Sums, products, minimums and maximums need at least one sub-packet, comparisons exactly two.
+fn check_arity(packet_id: u8, count: usize) -> Result<(), Reason> {
+    let valid = match packet_id {
+        5..=7 => count == 2,
+        _ => count >= 1,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Reason::Arity { packet_id, count })
+    }
+}
+
+impl Packet {
+    /// Panics if `version` doesn't fit into 3 bits.
+    pub fn literal(version: u8, value: u64) -> Self {
+        Self::big_literal(version, value.into())
+    }
+
+    /// Same as `literal`, for values of any size.
+    pub fn big_literal(version: u8, value: BigUint) -> Self {
+        assert!(version < 8, "version {} doesn't fit into 3 bits", version);
+
+        Self {
+            version,
+            packet_id: 4,
+            kind: Content::Literal(value),
+            span: None,
+        }
+    }
+
+    /// Panics if `version` or `packet_id` don't fit into 3 bits, `packet_id` is the ID of literals
+    /// or the number of sub-packets doesn't suit the operator.
+    pub fn operator(
+        version: u8,
+        packet_id: u8,
+        length_type: LengthType,
+        sub_packets: Vec<Packet>,
+    ) -> Self {
+        assert!(version < 8, "version {} doesn't fit into 3 bits", version);
+        assert!(
+            packet_id < 8 && packet_id != 4,
+            "{} isn't an operator packet ID",
+            packet_id
+        );
+        if let Err(reason) = check_arity(packet_id, sub_packets.len()) {
+            panic!("{}", reason);
+        }
+
+        Self {
+            version,
+            packet_id,
+            kind: Content::Operator(length_type, sub_packets),
+            span: None,
+        }
+    }
+
+    /// The expression computed by `evaluate`, like `(sum 1 (max 3 4) (lt 5 6))`.
+    pub fn to_expression(&self) -> String {
+        match &self.kind {
+            Content::Literal(n) => n.to_string(),
+            Content::Operator(_, packets) => format!(
+                "({} {})",
+                operator_name(self.packet_id),
+                packets
+                    .iter()
+                    .map(Self::to_expression)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ),
+        }
+    }
+
+    fn fmt_outline(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(
+            f,
+            "{}v{} ID {} ",
+            "  ".repeat(depth),
+            self.version,
+            self.packet_id
+        )?;
+
+        match &self.kind {
+            Content::Literal(n) => write!(f, "literal {}", n)?,
+            Content::Operator(LengthType::Bits, packets) => {
+                let bits: usize = packets
+                    .iter()
+                    .filter_map(|p| p.span.as_ref().map(|span| span.len()))
+                    .sum();
+                write!(f, "{}, length type 0", operator_name(self.packet_id))?;
+                if packets.iter().all(|p| p.span.is_some()) {
+                    write!(f, " ({} bits)", bits)?;
+                }
+            }
+            Content::Operator(LengthType::Count, packets) => write!(
+                f,
+                "{}, length type 1 ({} sub-packets)",
+                operator_name(self.packet_id),
+                packets.len()
+            )?,
+        }
+
+        if let Some(span) = &self.span {
+            write!(f, ", bits {}..{}", span.start, span.end)?;
+        }
+
+        if let Content::Operator(_, packets) = &self.kind {
+            for p in packets {
+                writeln!(f)?;
+                p.fmt_outline(f, depth + 1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn version_sum(&self) -> usize {
+        self.version as usize
+            + match &self.kind {
+                Content::Literal(_) => 0,
+                Content::Operator(_, rest) => rest.iter().map(|p| p.version_sum()).sum::<usize>(),
+            }
+    }
+
+    /// Evaluates the packet with 64 bit arithmetic, returns which packet overflowed if any did.
+    pub fn evaluate(&self) -> Result<u64, Overflow> {
+        let overflow = || Overflow {
+            packet_id: self.packet_id,
+            span: self.span.clone(),
+        };
+
+        match &self.kind {
+            Content::Literal(n) => u64::try_from(n).map_err(|_| overflow()),
+            Content::Operator(_, packets) => {
+                let values = packets
+                    .iter()
+                    .map(Self::evaluate)
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                match self.packet_id {
+                    0 => values
+                        .iter()
+                        .try_fold(0_u64, |sum, v| sum.checked_add(*v))
+                        .ok_or_else(overflow),
+                    1 => values
+                        .iter()
+                        .try_fold(1_u64, |product, v| product.checked_mul(*v))
+                        .ok_or_else(overflow),
+                    id => Ok(select(id, &values)),
+                }
+            }
+        }
+    }
+
+    /// Evaluates the packet without any limit on the size of the values.
+    pub fn evaluate_big(&self) -> BigUint {
+        match &self.kind {
+            Content::Literal(n) => n.clone(),
+            Content::Operator(_, packets) => {
+                let values: Vec<BigUint> = packets.iter().map(Self::evaluate_big).collect();
+
+                match self.packet_id {
+                    0 => values.into_iter().sum(),
+                    1 => values.into_iter().product(),
+                    id => select(id, &values),
+                }
+            }
+        }
+    }
+}
+
+/// The operators which pick one of the `values` or compare them, so they can't overflow.
+fn select<T: Ord + Clone + From<u8>>(packet_id: u8, values: &[T]) -> T {
+    match packet_id {
+        2 => values.iter().min().unwrap().clone(),
+        3 => values.iter().max().unwrap().clone(),
+        5 => T::from((values[0] > values[1]) as u8),
+        6 => T::from((values[0] < values[1]) as u8),
+        7 => T::from((values[0] == values[1]) as u8),
+        x => unreachable!("unknown packet ID {}", x),
+    }
+}
+
+/// A packet whose value doesn't fit into 64 bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub packet_id: u8,
+    /// Where the packet was decoded from, if it was.
+    pub span: Option<Range<usize>>,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = if self.packet_id == 4 {
+            "literal"
+        } else {
+            operator_name(self.packet_id)
+        };
+        write!(f, "{} overflows 64 bits", name)?;
+
+        if let Some(span) = &self.span {
+            write!(f, " at bits {}..{}", span.start, span.end)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Reads a sequence of bits from the most significant bit of the first byte on.
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// The number of bits read so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() * 8 - self.position
+    }
+
+    /// Reads the next `bits` bits (at most 64) as a big endian number.
+    pub fn read(&mut self, bits: usize) -> Result<u64, DecodeError> {
+        assert!(bits <= 64, "can't read {} bits at once", bits);
+
+        if bits > self.remaining() {
+            return Err(DecodeError {
+                offset: self.bytes.len() * 8,
+                reason: Reason::UnexpectedEnd,
+            });
+        }
+
+        let mut value = 0;
+        let mut left = bits;
+
+        while left > 0 {
+            let byte = self.bytes[self.position / 8] as u64;
+            let available = 8 - self.position % 8;
+            let taken = available.min(left);
+            let chunk = (byte >> (available - taken)) & ((1 << taken) - 1);
+
+            value = (value << taken) | chunk;
+            self.position += taken;
+            left -= taken;
+        }
+
+        Ok(value)
+    }
+}
+
+/// Collects bits into bytes, the reverse of `BitReader`.
+#[derive(Debug, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of bits written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends the lowest `bits` bits of `value`, most significant bit first.
+    pub fn write(&mut self, value: u64, bits: usize) {
+        for i in (0..bits).rev() {
+            if self.len.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 == 1 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+
+    pub fn append(&mut self, other: &BitWriter) {
+        let mut bits = BitReader::new(&other.bytes);
+
+        while bits.position() < other.len {
+            let chunk = (other.len - bits.position()).min(8);
+            self.write(bits.read(chunk).unwrap(), chunk);
+        }
+    }
+
+    /// The bytes written so far, the last one padded with zeros.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// How deep operators may be nested. Decoding, evaluating and even dropping packets recurse once
+/// per level, so this keeps deeply nested transmissions from overflowing the stack.
+pub const MAX_DEPTH: usize = 256;
+
+/// Decodes packets from a `BitReader`. With `trace` enabled every packet header is printed to
+/// stderr while decoding.
+pub struct Decoder<'a> {
+    bits: BitReader<'a>,
+    trace: bool,
+    depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bits: BitReader::new(bytes),
+            trace: false,
+            depth: 0,
+        }
+    }
+
+    pub fn with_trace(self, trace: bool) -> Self {
+        Self { trace, ..self }
+    }
+
+    /// The number of bits decoded so far.
+    pub fn position(&self) -> usize {
+        self.bits.position()
+    }
+
+    pub fn decode(&mut self) -> Result<Packet, DecodeError> {
+        let start = self.bits.position();
+        let version = self.bits.read(3)? as u8;
+        let packet_id = self.bits.read(3)? as u8;
+
+        if self.trace {
+            eprintln!(
+                "{}bit {}: version {}, packet ID {}",
+                "  ".repeat(self.depth),
+                start,
+                version,
+                packet_id
+            );
+        }
+
+        // literal packet
+        let kind = if packet_id == 4 {
+            Content::Literal(self.decode_literal()?)
+        // operator packet
+        } else {
+            if self.depth == MAX_DEPTH {
+                return Err(DecodeError {
+                    offset: start,
+                    reason: Reason::TooDeep,
+                });
+            }
+            self.depth += 1;
+
+            let content = match self.bits.read(1)? {
+                0 => {
+                    // Next 15 bits contain number of bits for sub packets
+                    let bit_count = self.bits.read(15)? as usize;
+                    Content::Operator(LengthType::Bits, self.decode_bits(bit_count)?)
+                }
+                _ => {
+                    // Next 11 bits represent number of subpackets
+                    let packet_count = self.bits.read(11)? as usize;
+                    Content::Operator(LengthType::Count, self.decode_multiple(packet_count)?)
+                }
+            };
+
+            self.depth -= 1;
+
+            if let Content::Operator(_, sub_packets) = &content {
+                check_arity(packet_id, sub_packets.len()).map_err(|reason| DecodeError {
+                    offset: start,
+                    reason,
+                })?;
+            }
+
+            content
+        };
+
+        Ok(Packet {
+            version,
+            packet_id,
+            kind,
+            span: Some(start..self.bits.position()),
+        })
+    }
+
+    /// Decodes n packets
+    pub fn decode_multiple(&mut self, count: usize) -> Result<Vec<Packet>, DecodeError> {
+        (0..count).map(|_| self.decode()).collect()
+    }
+
+    /// Decodes packets until `bit_count` bits are consumed
+    pub fn decode_bits(&mut self, bit_count: usize) -> Result<Vec<Packet>, DecodeError> {
+        let start = self.bits.position();
+        let end = start + bit_count;
+        let mut result = vec![];
+
+        while self.bits.position() < end {
+            result.push(self.decode()?);
+        }
+
+        if self.bits.position() != end {
+            return Err(DecodeError {
+                offset: self.bits.position(),
+                reason: Reason::LengthMismatch {
+                    expected: bit_count,
+                    actual: self.bits.position() - start,
+                },
+            });
+        }
+
+        Ok(result)
+    }
+
+    pub fn decode_literal(&mut self) -> Result<BigUint, DecodeError> {
+        let mut nibbles = vec![];
+
+        loop {
+            let group = self.bits.read(5)?;
+            nibbles.push((group & 0b1111) as u8);
+
+            if group & 0b10000 == 0 {
+                break;
+            }
+        }
+
+        Ok(BigUint::from_radix_be(&nibbles, 16).unwrap())
+    }
+
+    /// Checks that nothing but zeros follow.
+    pub fn finish(mut self) -> Result<(), DecodeError> {
+        while self.bits.remaining() > 0 {
+            let offset = self.bits.position();
+            if self.bits.read(1)? != 0 {
+                return Err(DecodeError {
+                    offset,
+                    reason: Reason::NonZeroPadding,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes the outermost packet of a transmission, which may only be followed by zeros.
+pub fn decode(bytes: &[u8]) -> Result<Packet, DecodeError> {
+    let mut decoder = Decoder::new(bytes);
+    let packet = decoder.decode()?;
+    decoder.finish()?;
+
+    Ok(packet)
+}
+
+/// Converts pairs of hex digits into bytes, an odd last digit is padded with zeros.
+pub fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            pair.iter()
+                .map(|c| (*c as char).to_digit(16).unwrap() as u8)
+                .chain(std::iter::once(0))
+                .take(2)
+                .fold(0, |byte, digit| (byte << 4) | digit)
+        })
+        .collect()
+}
+
+pub fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+/// Encodes `packet` as hex, padded with zeros to whole bytes. Panics if the sub-packets of an
+/// operator don't fit into its length field.
+pub fn encode(packet: &Packet) -> String {
+    let mut bits = BitWriter::new();
+    encode_bits(packet, &mut bits);
+
+    bytes_to_hex(&bits.into_bytes())
+}
+
+/// Appends the bits of `packet` to `bits`.
+pub fn encode_bits(packet: &Packet, bits: &mut BitWriter) {
+    bits.write(packet.version as u64, 3);
+    bits.write(packet.packet_id as u64, 3);
+
+    match &packet.kind {
+        Content::Literal(n) => encode_literal(n, bits),
+        Content::Operator(LengthType::Bits, packets) => {
+            let mut sub_packets = BitWriter::new();
+            for p in packets {
+                encode_bits(p, &mut sub_packets);
+            }
+
+            assert!(
+                sub_packets.len() < 1 << 15,
+                "{} bits of sub-packets don't fit into 15 bits",
+                sub_packets.len()
+            );
+            bits.write(0, 1);
+            bits.write(sub_packets.len() as u64, 15);
+            bits.append(&sub_packets);
+        }
+        Content::Operator(LengthType::Count, packets) => {
+            assert!(
+                packets.len() < 1 << 11,
+                "{} sub-packets don't fit into 11 bits",
+                packets.len()
+            );
+            bits.write(1, 1);
+            bits.write(packets.len() as u64, 11);
+            for p in packets {
+                encode_bits(p, bits);
+            }
+        }
+    }
+}
+
+/// Splits `n` into groups of 4 bits, each prefixed with 1 except for the last one.
+pub fn encode_literal(n: &BigUint, bits: &mut BitWriter) {
+    let nibbles = n.to_radix_be(16);
+
+    for (i, nibble) in nibbles.iter().enumerate() {
+        let prefix = if i + 1 < nibbles.len() { 0b10000 } else { 0 };
+        bits.write(prefix | *nibble as u64, 5);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(packet: &Packet) -> Packet {
+        decode(&hex_to_bytes(&encode(packet))).unwrap()
+    }
+
+    #[test]
+    fn test_bit_reader() {
+        let bytes = hex_to_bytes("D2FE28");
+        let mut bits = BitReader::new(&bytes);
+
+        assert_eq!(bits.read(3), Ok(6));
+        assert_eq!(bits.read(3), Ok(4));
+        assert_eq!(bits.read(15), Ok(0b101111111000101));
+        assert_eq!(bits.position(), 21);
+        assert_eq!(bits.remaining(), 3);
+        assert_eq!(hex_to_bytes("ABC"), vec![0xAB, 0xC0]);
+    }
+
+    #[test]
+    fn test_encode_examples() {
+        assert_eq!(encode(&Packet::literal(6, 2021)), "D2FE28");
+        assert_eq!(
+            encode(&Packet::operator(
+                1,
+                6,
+                LengthType::Bits,
+                vec![Packet::literal(6, 10), Packet::literal(2, 20)]
+            )),
+            "38006F45291200"
+        );
+        assert_eq!(
+            encode(&Packet::operator(
+                7,
+                3,
+                LengthType::Count,
+                vec![
+                    Packet::literal(2, 1),
+                    Packet::literal(4, 2),
+                    Packet::literal(1, 3)
+                ]
+            )),
+            "EE00D40C823060"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let packets = [
+            Packet::literal(0, 0),
+            Packet::literal(7, u64::MAX),
+            Packet::big_literal(6, BigUint::from(u64::MAX) * 1000_u32),
+            Packet::operator(
+                3,
+                0,
+                LengthType::Bits,
+                vec![
+                    Packet::literal(1, 15),
+                    Packet::operator(
+                        2,
+                        1,
+                        LengthType::Count,
+                        vec![Packet::literal(5, 16), Packet::literal(0, 1 << 40)],
+                    ),
+                ],
+            ),
+            Packet::operator(
+                5,
+                7,
+                LengthType::Count,
+                vec![
+                    Packet::operator(0, 2, LengthType::Bits, vec![Packet::literal(4, 9)]),
+                    Packet::operator(6, 3, LengthType::Count, vec![Packet::literal(2, 0)]),
+                ],
+            ),
+        ];
+
+        for packet in &packets {
+            assert_eq!(&round_trip(packet), packet);
+        }
+    }
+
+    fn decode_error(hex: &str) -> DecodeError {
+        decode(&hex_to_bytes(hex)).unwrap_err()
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        // A literal cut off after its second group.
+        assert_eq!(
+            decode_error("D2FE"),
+            DecodeError {
+                offset: 16,
+                reason: Reason::UnexpectedEnd
+            }
+        );
+        // The first example with one bit of its padding set.
+        assert_eq!(
+            decode_error("D2FE29"),
+            DecodeError {
+                offset: 23,
+                reason: Reason::NonZeroPadding
+            }
+        );
+        // An equality check with three sub-packets.
+        let mut bits = BitWriter::new();
+        bits.write(0, 3);
+        bits.write(7, 3);
+        bits.write(1, 1);
+        bits.write(3, 11);
+        for _ in 0..3 {
+            encode_bits(&Packet::literal(0, 1), &mut bits);
+        }
+        assert_eq!(
+            decode(&bits.into_bytes()).unwrap_err(),
+            DecodeError {
+                offset: 0,
+                reason: Reason::Arity {
+                    packet_id: 7,
+                    count: 3
+                }
+            }
+        );
+        // A sum announcing 10 bits of sub-packets, but containing one of 11 bits.
+        let mut bits = BitWriter::new();
+        bits.write(0, 7);
+        bits.write(10, 15);
+        encode_bits(&Packet::literal(0, 1), &mut bits);
+        assert_eq!(
+            decode(&bits.into_bytes()).unwrap_err(),
+            DecodeError {
+                offset: 33,
+                reason: Reason::LengthMismatch {
+                    expected: 10,
+                    actual: 11
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn test_max_depth() {
+        // Sums of a single sub-packet around a literal, `depth` of them.
+        let nested = |depth: usize| {
+            let packet = (0..depth).fold(Packet::literal(0, 7), |packet, _| {
+                Packet::operator(0, 0, LengthType::Count, vec![packet])
+            });
+            hex_to_bytes(&encode(&packet))
+        };
+
+        let packet = decode(&nested(MAX_DEPTH)).unwrap();
+        assert_eq!(packet.evaluate(), Ok(7));
+
+        assert_eq!(
+            decode(&nested(MAX_DEPTH + 1)).unwrap_err(),
+            DecodeError {
+                offset: MAX_DEPTH * 18,
+                reason: Reason::TooDeep
+            }
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let packet = decode(&hex_to_bytes("9C0141080250320F1802104A08")).unwrap();
+
+        assert_eq!(packet.to_expression(), "(eq (sum 1 3) (product 2 2))");
+        assert_eq!(
+            packet.to_string(),
+            "v4 ID 7 eq, length type 0 (80 bits), bits 0..102
+  v2 ID 0 sum, length type 1 (2 sub-packets), bits 22..62
+    v2 ID 4 literal 1, bits 40..51
+    v4 ID 4 literal 3, bits 51..62
+  v6 ID 1 product, length type 1 (2 sub-packets), bits 62..102
+    v0 ID 4 literal 2, bits 80..91
+    v2 ID 4 literal 2, bits 91..102"
+        );
+    }
+
+    #[test]
+    fn test_evaluate_overflow() {
+        let packet = Packet::operator(
+            0,
+            1,
+            LengthType::Count,
+            vec![
+                Packet::literal(0, 1_u64 << 40),
+                Packet::literal(0, 1_u64 << 40),
+            ],
+        );
+        let decoded = decode(&hex_to_bytes(&encode(&packet))).unwrap();
+
+        assert_eq!(
+            decoded.evaluate().unwrap_err().to_string(),
+            "product overflows 64 bits at bits 0..140"
+        );
+        assert_eq!(decoded.evaluate_big(), BigUint::from(1_u32) << 80);
+
+        let packet = Packet::operator(
+            0,
+            6,
+            LengthType::Bits,
+            vec![
+                Packet::literal(0, 5),
+                Packet::big_literal(0, BigUint::from(1_u32) << 70),
+            ],
+        );
+        assert_eq!(
+            packet.evaluate(),
+            Err(Overflow {
+                packet_id: 4,
+                span: None
+            })
+        );
+        assert_eq!(packet.evaluate_big(), BigUint::from(1_u32));
+    }
+}
@@ -0,0 +1,124 @@
+use itertools::Itertools;
+
+use crate::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day17;
+
+pub type Point = (i32, i32);
+pub type V2 = (i32, i32);
+
+#[derive(Debug, Clone)]
+pub struct Rect {
+    from: Point,
+    to: Point,
+}
+
+impl Rect {
+    pub fn contains(&self, point: &Point) -> bool {
+        self.from.0 <= point.0
+            && self.to.0 >= point.0
+            && self.from.1 <= point.1
+            && self.to.1 >= point.1
+    }
+
+    pub fn bottom_right(&self) -> Point {
+        (self.to.0, self.from.1)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Path {
+    current_pos: Point,
+    current_vec: V2,
+    max_pos: Point,
+}
+
+impl Iterator for Path {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_pos.1 < self.max_pos.1 {
+            return None;
+        }
+
+        let old_pos = self.current_pos;
+
+        self.current_pos.0 += self.current_vec.0;
+        self.current_pos.1 += self.current_vec.1;
+
+        self.current_vec.0 -= self.current_vec.0.signum();
+        self.current_vec.1 -= 1;
+
+        Some(old_pos)
+    }
+}
+
+pub fn velocitys_on_target(start: Point, rect: Rect) -> impl Iterator<Item = V2> {
+    (1..=rect.to.0)
+        .cartesian_product(rect.from.1..rect.from.1.abs())
+        .filter_map(move |(x, y)| {
+            let v = (x, y);
+            let mut path = Path {
+                current_pos: start,
+                current_vec: v,
+                max_pos: rect.bottom_right(),
+            };
+
+            path.find(|pos| rect.contains(pos)).map(|_| v)
+        })
+}
+
+impl Solution for Day17 {
+    type Input = Rect;
+    type Output1 = Answer<i32>;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_target(input)
+    }
+
+    fn part_1(rect: &Self::Input) -> Answer<i32> {
+        let highest = velocitys_on_target((0, 0), rect.clone())
+            .flat_map(|v| {
+                Path {
+                    current_pos: (0, 0),
+                    current_vec: v,
+                    max_pos: rect.bottom_right(),
+                }
+                .map(|p| p.1)
+            })
+            .max();
+
+        match highest {
+            Some(highest) => Answer::Found(highest),
+            None => Answer::None("no velocity hits the target area".to_string()),
+        }
+    }
+
+    fn part_2(rect: &Self::Input) -> usize {
+        velocitys_on_target((0, 0), rect.clone()).count()
+    }
+}
+
+/// Parses a target area like `target area: x=230..283, y=-107..-57`.
+pub fn parse_target(input: &str) -> Result<Rect, ParseError> {
+    let line = input.lines().next().unwrap_or("");
+
+    let rest = parse::strip_prefix(line, line, "target area: ")?;
+    let (x, y) = parse::split_once(line, rest, ", ")?;
+
+    let range = |s: &str, axis: &str| -> Result<(i32, i32), ParseError> {
+        let s = parse::strip_prefix(line, s, axis)?;
+        let (from, to) = parse::split_once(line, s, "..")?;
+        Ok((parse::number(line, from)?, parse::number(line, to)?))
+    };
+
+    let (x_from, x_to) = range(x, "x=")?;
+    let (y_from, y_to) = range(y, "y=")?;
+
+    Ok(Rect {
+        from: (x_from, y_from),
+        to: (x_to, y_to),
+    })
+}
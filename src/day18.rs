@@ -0,0 +1,623 @@
+use std::fmt;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day18;
+
+/// The deepest pairs may be nested inside this many pairs, deeper ones explode.
+const MAX_NESTING: u32 = 4;
+
+/// The smallest regular number which splits.
+const SPLIT_AT: u32 = 10;
+
+/// How deep pairs may be nested in the input. Parsing and printing recurse once per level, so
+/// this keeps deeply nested numbers from overflowing the stack.
+const MAX_DEPTH: u32 = 256;
+
+/// How much the regular numbers of an input line may add up to. Explosions and splits keep the
+/// sum of a number, so reducing the sum of two lines can't overflow.
+const MAX_SUM: u32 = u32::MAX / 2;
+
+/// A regular number together with the number of pairs it's nested in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Leaf {
+    value: u32,
+    depth: u32,
+}
+
+/// A snailfish number stored as its regular numbers from left to right. Their depths are enough
+/// to restore the pairs: two neighbours at the same depth, without a deeper one between, belong
+/// to the same pair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnailfishNumber {
+    leaves: Vec<Leaf>,
+}
+
+/// What a step of the reduction did, see `add_with_steps`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Addition,
+    /// A pair of regular numbers nested inside `depth` pairs exploded.
+    Explode {
+        left: u32,
+        right: u32,
+        depth: u32,
+    },
+    Split(u32),
+}
+
+impl Action {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Addition => "addition",
+            Self::Explode { .. } => "explode",
+            Self::Split(_) => "split",
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            Self::Addition => write!(f, "addition"),
+            Self::Explode { left, right, depth } => {
+                write!(f, "explode [{},{}] at depth {}", left, right, depth)
+            }
+            Self::Split(value) => write!(f, "split {}", value),
+        }
+    }
+}
+
+/// An action together with the number it gave.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub action: Action,
+    pub number: SnailfishNumber,
+}
+
+impl SnailfishNumber {
+    /// Applies the leftmost explosion or split until there's none left, recording each of them
+    /// in `steps` if given.
+    fn reduce(&mut self, mut steps: Option<&mut Vec<Step>>) {
+        self.explode_all(steps.as_deref_mut());
+
+        // Nothing left of `i` splits. A split can only create a pair which is too deep at `i`
+        // itself, which explodes right away and may leave its left neighbour large enough to
+        // split.
+        let mut i = 0;
+        while i < self.leaves.len() {
+            if self.leaves[i].value < SPLIT_AT {
+                i += 1;
+                continue;
+            }
+
+            let action = self.split(i);
+            self.record(steps.as_deref_mut(), action);
+
+            if self.leaves[i].depth > MAX_NESTING {
+                let action = self.explode(i);
+                self.record(steps.as_deref_mut(), action);
+                i = i.saturating_sub(1);
+            }
+        }
+    }
+
+    fn record(&self, steps: Option<&mut Vec<Step>>, action: Action) {
+        if let Some(steps) = steps {
+            steps.push(Step {
+                action,
+                number: self.clone(),
+            });
+        }
+    }
+
+    /// Explodes every pair of regular numbers which is nested too deep, from left to right,
+    /// including the pairs which only become regular by an explosion in one of their halves.
+    fn explode_all(&mut self, mut steps: Option<&mut Vec<Step>>) {
+        // The leaves before `len` are done, the ones after `read` haven't been looked at. They
+        // make up the current number.
+        let mut len = 0;
+        // The halves whose sibling hasn't been seen yet, as their depth and first leaf.
+        let mut halves: Vec<(u32, usize)> = vec![];
+
+        for read in 0..self.leaves.len() {
+            self.leaves[len] = self.leaves[read];
+            len += 1;
+
+            let mut half = (self.leaves[len - 1].depth, len - 1);
+            while let Some(&(depth, start)) = halves.last().filter(|(depth, _)| *depth == half.0) {
+                halves.pop();
+
+                if depth > MAX_NESTING && len - start == 2 {
+                    let (left, right) = (self.leaves[start], self.leaves[start + 1]);
+                    if start > 0 {
+                        self.leaves[start - 1].value += left.value;
+                    }
+                    if let Some(next) = self.leaves.get_mut(read + 1) {
+                        next.value += right.value;
+                    }
+                    self.leaves[start] = Leaf {
+                        value: 0,
+                        depth: depth - 1,
+                    };
+                    len -= 1;
+
+                    if let Some(steps) = steps.as_deref_mut() {
+                        let leaves = &self.leaves;
+                        steps.push(Step {
+                            action: Action::Explode {
+                                left: left.value,
+                                right: right.value,
+                                depth: depth - 1,
+                            },
+                            number: SnailfishNumber {
+                                leaves: leaves[..len]
+                                    .iter()
+                                    .chain(&leaves[read + 1..])
+                                    .copied()
+                                    .collect(),
+                            },
+                        });
+                    }
+                }
+
+                half = (depth - 1, start);
+            }
+            halves.push(half);
+        }
+
+        self.leaves.truncate(len);
+    }
+
+    /// Replaces the pair starting at `i` with 0, adding its values to its neighbours.
+    fn explode(&mut self, i: usize) -> Action {
+        let (left, right) = (self.leaves[i], self.leaves[i + 1]);
+
+        if i > 0 {
+            self.leaves[i - 1].value += left.value;
+        }
+        if let Some(next) = self.leaves.get_mut(i + 2) {
+            next.value += right.value;
+        }
+
+        self.leaves[i] = Leaf {
+            value: 0,
+            depth: left.depth - 1,
+        };
+        self.leaves.remove(i + 1);
+
+        Action::Explode {
+            left: left.value,
+            right: right.value,
+            depth: left.depth - 1,
+        }
+    }
+
+    /// Replaces the regular number at `i` with a pair of its halves, rounding down on the left.
+    fn split(&mut self, i: usize) -> Action {
+        let Leaf { value, depth } = self.leaves[i];
+        let half = |value| Leaf {
+            value,
+            depth: depth + 1,
+        };
+
+        self.leaves[i] = half(value / 2);
+        self.leaves.insert(i + 1, half(value - value / 2));
+
+        Action::Split(value)
+    }
+}
+
+impl fmt::Display for SnailfishNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        fn write_node(
+            f: &mut fmt::Formatter<'_>,
+            leaves: &mut std::slice::Iter<Leaf>,
+            depth: u32,
+        ) -> Result<(), fmt::Error> {
+            match leaves.as_slice().first() {
+                Some(leaf) if leaf.depth > depth => {
+                    write!(f, "[")?;
+                    write_node(f, leaves, depth + 1)?;
+                    write!(f, ",")?;
+                    write_node(f, leaves, depth + 1)?;
+                    write!(f, "]")
+                }
+                _ => write!(f, "{}", leaves.next().ok_or(fmt::Error)?.value),
+            }
+        }
+
+        write_node(f, &mut self.leaves.iter(), 0)
+    }
+}
+
+/// The first character of `s`, or an empty slice at the end of the line.
+fn first_char(s: &str) -> &str {
+    &s[..s.chars().next().map_or(0, |c| c.len_utf8())]
+}
+
+/// Parses the number at the start of `part`, nested in `depth` pairs, appending its regular
+/// numbers to `leaves` and adding them to `sum`. Returns what's left of `part`, whitespace around
+/// the number is skipped.
+fn parse_snailfish_number<'a>(
+    line: &str,
+    part: &'a str,
+    depth: u32,
+    leaves: &mut Vec<Leaf>,
+    sum: &mut u32,
+) -> Result<&'a str, ParseError> {
+    let part = part.trim_start();
+
+    if let Some(rest) = part.strip_prefix('[') {
+        if depth == MAX_DEPTH {
+            return Err(ParseError::new(
+                line,
+                &part[..1],
+                format!("pairs nested more than {} deep", MAX_DEPTH),
+            ));
+        }
+
+        let rest = parse_snailfish_number(line, rest, depth + 1, leaves, sum)?;
+        if !rest.starts_with(',') {
+            return Err(ParseError::new(line, first_char(rest), "expected ','"));
+        }
+        let rest = parse_snailfish_number(line, &rest[1..], depth + 1, leaves, sum)?;
+        if !rest.starts_with(']') {
+            return Err(ParseError::new(line, first_char(rest), "expected ']'"));
+        }
+        return Ok(rest[1..].trim_start());
+    }
+
+    let end = part
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(part.len());
+    if end == 0 {
+        return Err(ParseError::new(
+            line,
+            first_char(part),
+            "expected '[' or a number",
+        ));
+    }
+
+    let value = parse::number(line, &part[..end])?;
+    *sum = sum
+        .checked_add(value)
+        .filter(|sum| *sum <= MAX_SUM)
+        .ok_or_else(|| {
+            ParseError::new(
+                line,
+                &part[..end],
+                format!("regular numbers add up to more than {}", MAX_SUM),
+            )
+        })?;
+
+    leaves.push(Leaf { value, depth });
+    Ok(part[end..].trim_start())
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    /// Parses a number like `[[1,2], 13]`, which doesn't have to be reduced.
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        let mut leaves = vec![];
+        let rest = parse_snailfish_number(line, line, 0, &mut leaves, &mut 0)?;
+
+        if !rest.is_empty() {
+            return Err(ParseError::new(line, rest, "expected end of line"));
+        }
+
+        Ok(Self { leaves })
+    }
+}
+
+pub fn magnitude(number: &SnailfishNumber) -> u32 {
+    // Reduces the innermost pairs to their magnitude as soon as both halves are known.
+    let mut stack: Vec<Leaf> = vec![];
+
+    for &leaf in &number.leaves {
+        let mut top = leaf;
+        while let Some(&left) = stack.last().filter(|left| left.depth == top.depth) {
+            stack.pop();
+            top = Leaf {
+                value: 3 * left.value + 2 * top.value,
+                depth: top.depth - 1,
+            };
+        }
+        stack.push(top);
+    }
+
+    stack[0].value
+}
+
+/// The pair of `a` and `b`, before it's reduced.
+fn pair(a: &SnailfishNumber, b: &SnailfishNumber) -> SnailfishNumber {
+    SnailfishNumber {
+        leaves: a
+            .leaves
+            .iter()
+            .chain(&b.leaves)
+            .map(|leaf| Leaf {
+                depth: leaf.depth + 1,
+                ..*leaf
+            })
+            .collect(),
+    }
+}
+
+pub fn add(a: &SnailfishNumber, b: &SnailfishNumber) -> SnailfishNumber {
+    let mut result = pair(a, b);
+
+    result.reduce(None);
+    result
+}
+
+/// Adds `a` and `b` like `add`, returning the number after the addition and after every
+/// explosion and split. The last one is the sum.
+pub fn add_with_steps(a: &SnailfishNumber, b: &SnailfishNumber) -> Vec<Step> {
+    let mut result = pair(a, b);
+    let mut steps = vec![Step {
+        action: Action::Addition,
+        number: result.clone(),
+    }];
+
+    result.reduce(Some(&mut steps));
+    steps
+}
+
+/// Renders `steps` like the walkthrough in the puzzle, one line per step.
+pub fn render_steps(steps: &[Step]) -> String {
+    steps
+        .iter()
+        .map(|step| {
+            format!(
+                "{:<15} {}",
+                format!("after {}:", step.action.name()),
+                step.number
+            )
+        })
+        .join("\n")
+}
+
+impl Solution for Day18 {
+    type Input = Vec<SnailfishNumber>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let numbers: Vec<SnailfishNumber> = parse::parse_lines(input, str::parse)?;
+
+        // Part 2 adds two different numbers.
+        if numbers.len() < 2 {
+            return Err(ParseError::end_of_input(
+                input,
+                "expected at least two snailfish numbers",
+            ));
+        }
+
+        Ok(numbers)
+    }
+
+    fn part_1(values: &Self::Input) -> u32 {
+        magnitude(
+            &values[1..]
+                .iter()
+                .fold(values[0].clone(), |sum, x| add(&sum, x)),
+        )
+    }
+
+    fn part_2(values: &Self::Input) -> u32 {
+        (0..values.len())
+            .cartesian_product(0..values.len())
+            .filter(|(i, j)| i != j)
+            .map(|(i, j)| magnitude(&add(&values[i], &values[j])))
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOMEWORK: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    fn number(line: &str) -> SnailfishNumber {
+        line.parse().unwrap()
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(
+            add(&number("[[[[4,3],4],4],[7,[[8,4],9]]]"), &number("[1,1]")).to_string(),
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        );
+
+        let sum = |lines: &str| {
+            let numbers = Day18::parse(lines).unwrap();
+            numbers[1..]
+                .iter()
+                .fold(numbers[0].clone(), |sum, x| add(&sum, x))
+                .to_string()
+        };
+
+        assert_eq!(
+            sum("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]"),
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]"
+        );
+        assert_eq!(
+            sum("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]
+[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]
+[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]
+[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]
+[7,[5,[[3,8],[1,4]]]]
+[[2,[2,2]],[8,[8,1]]]
+[2,9]
+[1,[[[9,3],9],[[9,0],[0,7]]]]
+[[[5,[7,4]],7],1]
+[[[[4,2],2],6],[8,7]]"),
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+        );
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(magnitude(&number("[9,1]")), 29);
+        assert_eq!(magnitude(&number("[[1,2],[[3,4],5]]")), 143);
+        assert_eq!(
+            magnitude(&number(
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+            )),
+            3488
+        );
+
+        let homework = Day18::parse(HOMEWORK).unwrap();
+        assert_eq!(Day18::part_1(&homework), 4140);
+        assert_eq!(Day18::part_2(&homework), 3993);
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = number(" [ [[[0,7], 4],[15 ,[0,13]]], [1,1] ]\t");
+        assert_eq!(parsed.to_string(), "[[[[0,7],4],[15,[0,13]]],[1,1]]");
+
+        let error = |line: &str| {
+            let e = line.parse::<SnailfishNumber>().unwrap_err();
+            (e.column, e.token, e.message)
+        };
+
+        assert_eq!(error(""), (1, "".into(), "expected '[' or a number".into()));
+        assert_eq!(error("[1 2]"), (4, "2".into(), "expected ','".into()));
+        assert_eq!(
+            error("[1,[2,x]]"),
+            (7, "x".into(), "expected '[' or a number".into())
+        );
+        assert_eq!(error("[1,2"), (5, "".into(), "expected ']'".into()));
+        assert_eq!(
+            error("[1,2]]"),
+            (6, "]".into(), "expected end of line".into())
+        );
+        assert_eq!(
+            error("[1,99999999999]"),
+            (4, "99999999999".into(), "expected a number".into())
+        );
+        assert_eq!(
+            error("[[[[[4000000000,4000000000],1],1],1],1]"),
+            (
+                6,
+                "4000000000".into(),
+                "regular numbers add up to more than 2147483647".into()
+            )
+        );
+        assert_eq!(
+            error("[2000000000, 2000000000]"),
+            (
+                14,
+                "2000000000".into(),
+                "regular numbers add up to more than 2147483647".into()
+            )
+        );
+        assert_eq!(
+            Day18::parse("[1,1]\n[2,-2]").unwrap_err().to_string(),
+            "line 2, column 4: expected '[' or a number, found '-'"
+        );
+        assert_eq!(
+            Day18::parse("[1,1]\n").unwrap_err().to_string(),
+            "line 2, column 1: expected at least two snailfish numbers, found end of line"
+        );
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let nested = |depth: u32| {
+            let depth = depth as usize;
+            format!("{}1{}", "[".repeat(depth), ",1]".repeat(depth))
+        };
+
+        let deepest = number(&nested(MAX_DEPTH));
+        assert_eq!(deepest.to_string(), nested(MAX_DEPTH));
+        assert_eq!(magnitude(&add(&deepest, &deepest)), 4128);
+
+        for depth in [MAX_DEPTH + 1, 200_000] {
+            let e = nested(depth).parse::<SnailfishNumber>().unwrap_err();
+            assert_eq!(
+                (e.column, e.token, e.message),
+                (257, "[".into(), "pairs nested more than 256 deep".into())
+            );
+        }
+    }
+
+    #[test]
+    fn test_reduce_unreduced() {
+        let reduced = |line: &str| {
+            let mut number = number(line);
+            number.reduce(None);
+            number.to_string()
+        };
+
+        assert_eq!(
+            reduced("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"),
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        );
+        assert_eq!(
+            reduced("[[[[0,7],4],[15,[0,13]]],[1,1]]"),
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        );
+        // [1,2] explodes first, which makes [0,5] a pair of regular numbers.
+        assert_eq!(reduced("[[[[[[1,2],3],4],5],6],7]"), "[[[[0,9],5],6],7]");
+    }
+
+    #[test]
+    fn test_add_with_steps() {
+        let steps = add_with_steps(&number("[[[[4,3],4],4],[7,[[8,4],9]]]"), &number("[1,1]"));
+
+        assert_eq!(
+            render_steps(&steps),
+            "after addition: [[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]
+after explode:  [[[[0,7],4],[7,[[8,4],9]]],[1,1]]
+after explode:  [[[[0,7],4],[15,[0,13]]],[1,1]]
+after split:    [[[[0,7],4],[[7,8],[0,13]]],[1,1]]
+after split:    [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]
+after explode:  [[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+        );
+        assert_eq!(
+            steps
+                .iter()
+                .map(|step| step.action.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "addition",
+                "explode [4,3] at depth 4",
+                "explode [8,4] at depth 4",
+                "split 15",
+                "split 13",
+                "explode [6,7] at depth 4",
+            ]
+        );
+
+        for (a, b) in HOMEWORK.lines().tuple_windows() {
+            let (a, b) = (number(a), number(b));
+            assert_eq!(add_with_steps(&a, &b).last().unwrap().number, add(&a, &b));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        for line in HOMEWORK.lines().chain(["7", "[1,[2,[3,[4,[5,6]]]]]"]) {
+            assert_eq!(number(line).to_string(), line);
+        }
+    }
+}
@@ -1,17 +1,21 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::read_to_string;
+
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day19;
 
 #[derive(Debug, Hash, PartialEq, Eq, Copy, Clone, PartialOrd, Ord)]
-struct Beacon {
-    x: i32,
-    y: i32,
-    z: i32,
+pub struct Beacon {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
 }
 
 impl Beacon {
-    fn rotation(&self, index: usize) -> Beacon {
+    pub fn rotation(&self, index: usize) -> Beacon {
         match index {
             0 => Beacon {
                 x: self.x,
@@ -150,7 +154,7 @@ impl Beacon {
         }
     }
 
-    fn add(&self, other: &Beacon) -> Beacon {
+    pub fn add(&self, other: &Beacon) -> Beacon {
         Beacon {
             x: self.x + other.x,
             y: self.y + other.y,
@@ -158,7 +162,7 @@ impl Beacon {
         }
     }
 
-    fn sub(&self, other: &Beacon) -> Beacon {
+    pub fn sub(&self, other: &Beacon) -> Beacon {
         Beacon {
             x: self.x - other.x,
             y: self.y - other.y,
@@ -166,7 +170,7 @@ impl Beacon {
         }
     }
 
-    fn negate(&self) -> Beacon {
+    pub fn negate(&self) -> Beacon {
         Beacon {
             x: -self.x,
             y: -self.y,
@@ -174,35 +178,45 @@ impl Beacon {
         }
     }
 
-    fn distance(&self, other: &Beacon) -> i32 {
+    pub fn distance(&self, other: &Beacon) -> i32 {
         (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
     }
 }
 
 #[derive(Debug)]
-struct Scanner(Vec<Beacon>);
+pub struct Scanner(pub Vec<Beacon>);
 
-fn parse_input(input: &str) -> Vec<Scanner> {
+pub fn parse_input(input: &str) -> Result<Vec<Scanner>, ParseError> {
     let mut result = vec![];
-    for block in input.split("\n\n") {
-        let mut beacons = vec![];
-        for line in block.lines().skip(1) {
-            let coords = line
-                .split(",")
-                .map(|c| c.parse::<i32>().unwrap())
-                .collect_vec();
+
+    for (i, line) in input.lines().enumerate() {
+        let on_line = |e: ParseError| e.on_line(i + 1);
+
+        if line.is_empty() {
+            continue;
+        } else if line.starts_with("---") {
+            result.push(Scanner(vec![]));
+        } else {
+            let Some(Scanner(beacons)) = result.last_mut() else {
+                return Err(on_line(ParseError::new(
+                    line,
+                    line,
+                    "expected a '--- scanner N ---' header",
+                )));
+            };
+
+            let (x, rest) = parse::split_once(line, line, ",").map_err(on_line)?;
+            let (y, z) = parse::split_once(line, rest, ",").map_err(on_line)?;
 
             beacons.push(Beacon {
-                x: coords[0],
-                y: coords[1],
-                z: coords[2],
+                x: parse::number(line, x).map_err(on_line)?,
+                y: parse::number(line, y).map_err(on_line)?,
+                z: parse::number(line, z).map_err(on_line)?,
             });
         }
-
-        result.push(Scanner(beacons));
     }
 
-    result
+    Ok(result)
 }
 
 fn pairs_match(pairs: &[(Beacon, Beacon)]) -> bool {
@@ -212,19 +226,18 @@ fn pairs_match(pairs: &[(Beacon, Beacon)]) -> bool {
 
     let assumed_pos = pairs[0].0.sub(&pairs[0].1);
 
-    pairs[1..].iter().all(|(a, b)| a.sub(&b) == assumed_pos)
+    pairs[1..].iter().all(|(a, b)| a.sub(b) == assumed_pos)
 }
 
 fn solve(
     beacon_1s: &mut Vec<Beacon>,
     beacon_2s: &mut Vec<Beacon>,
     matching_pairs: &mut Vec<(Beacon, Beacon)>,
-    rotation: usize,
 ) -> Option<Beacon> {
-    if !pairs_match(&matching_pairs) {
+    if !pairs_match(matching_pairs) {
         return None;
     }
-    if pairs_match(&matching_pairs) && matching_pairs.len() >= 6 {
+    if pairs_match(matching_pairs) && matching_pairs.len() >= 6 {
         let (a, b) = matching_pairs[0];
         return Some(b.sub(&a));
     }
@@ -236,7 +249,7 @@ fn solve(
             let p = (c1, c2);
             matching_pairs.push(p);
 
-            if let Some(pos) = solve(beacon_1s, beacon_2s, matching_pairs, rotation) {
+            if let Some(pos) = solve(beacon_1s, beacon_2s, matching_pairs) {
                 return Some(pos);
             }
 
@@ -249,11 +262,50 @@ fn solve(
     None
 }
 
-fn main() {
-    let input = read_to_string("inputs/day19.txt").expect("file not found");
+/// All scanners together with their position and rotation (relative to scanner 0).
+pub struct Map {
+    scanners: Vec<Scanner>,
+    known: HashMap<usize, (Beacon, usize)>,
+}
+
+impl Solution for Day19 {
+    type Input = Map;
+    type Output1 = usize;
+    type Output2 = i32;
 
-    let scanners = parse_input(&input);
+    // Locating the scanners is the expensive part both parts depend on, so it's done once up
+    // front.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let scanners = parse_input(input)?;
+        let known = locate_scanners(&scanners);
+
+        Ok(Map { scanners, known })
+    }
+
+    fn part_1(map: &Self::Input) -> usize {
+        map.scanners
+            .iter()
+            .enumerate()
+            .flat_map(|(index, Scanner(scanner))| {
+                let (scanner_pos, scanner_rotation) = map.known.get(&index).unwrap();
+                scanner
+                    .iter()
+                    .map(|b| b.rotation(*scanner_rotation).add(scanner_pos))
+            })
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    fn part_2(map: &Self::Input) -> i32 {
+        (0..map.scanners.len())
+            .combinations(2)
+            .map(|v| map.known[&v[0]].0.distance(&map.known[&v[1]].0))
+            .max()
+            .unwrap()
+    }
+}
 
+pub fn locate_scanners(scanners: &[Scanner]) -> HashMap<usize, (Beacon, usize)> {
     let mut known: HashMap<usize, (Beacon, usize)> = HashMap::new();
     let mut unknown: HashSet<usize> = HashSet::new();
     known.insert(0, (Beacon { x: 0, y: 0, z: 0 }, 0));
@@ -278,7 +330,7 @@ fn main() {
                         .iter()
                         .map(|b| b.rotation(i))
                         .collect();
-                    if let Some(pos) = solve(&mut base, &mut search, &mut pairs, i) {
+                    if let Some(pos) = solve(&mut base, &mut search, &mut pairs) {
                         known.insert(index_unknown, (pos.sub(&reference_pos).negate(), i));
                         unknown.remove(&index_unknown);
 
@@ -293,23 +345,5 @@ fn main() {
         }
     }
 
-    let all_beacons = scanners
-        .iter()
-        .enumerate()
-        .flat_map(|(index, Scanner(scanner))| {
-            let (scanner_pos, scanner_rotation) = known.get(&index).unwrap();
-            scanner
-                .iter()
-                .map(|b| b.rotation(*scanner_rotation).add(scanner_pos))
-        })
-        .collect::<HashSet<_>>();
-
-    println!("Part 1: {:?}", all_beacons.len());
-
-    let part_2 = (0..scanners.len())
-        .combinations(2)
-        .map(|v| known[&v[0]].0.distance(&known[&v[1]].0))
-        .max();
-
-    println!("Part 2: {:?}", part_2);
+    known
 }
@@ -0,0 +1,151 @@
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day20;
+
+/// The pixels of an infinite image. Everything outside of `pixels` has the value `default`.
+#[derive(Debug, Clone)]
+pub struct Image {
+    pixels: Grid<bool>,
+    default: bool,
+}
+
+impl Image {
+    fn get(&self, row: usize, col: usize, offset: (isize, isize)) -> bool {
+        self.pixels
+            .offset((row, col), offset)
+            .map_or(self.default, |pos| self.pixels[pos])
+    }
+
+    pub fn count_lights(&self) -> usize {
+        self.pixels.values().filter(|x| **x).count()
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered = self
+            .pixels
+            .render(|_, pixel| if *pixel { '#' } else { '.' });
+
+        write!(f, "{}", rendered)
+    }
+}
+
+fn parse_pixels(line: &str) -> Result<Vec<bool>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new(
+                line,
+                &line[i..i + c.len_utf8()],
+                "expected '#' or '.'",
+            )),
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<bool>, Image), ParseError> {
+    let mut lines = input.lines().enumerate();
+
+    let index = match lines.next() {
+        Some((_, line)) => parse_pixels(line)?,
+        None => {
+            return Err(ParseError::end_of_line(
+                "",
+                "expected an enhancement algorithm",
+            ))
+        }
+    };
+
+    if index.len() != 512 {
+        let line = input.lines().next().unwrap();
+        return Err(ParseError::new(
+            line,
+            line,
+            "expected an enhancement algorithm of 512 pixels",
+        ));
+    }
+
+    // The image starts after the blank line following the algorithm.
+    let skipped = input.lines().take_while(|l| !l.is_empty()).count()
+        + input
+            .lines()
+            .skip_while(|l| !l.is_empty())
+            .take_while(|l| l.is_empty())
+            .count();
+    let image = input.lines().skip(skipped).collect::<Vec<_>>().join("\n");
+
+    let pixels = Grid::parse(&image, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .map_err(|e| {
+        let line = e.line + skipped;
+        e.on_line(line)
+    })?;
+
+    Ok((
+        index,
+        Image {
+            pixels,
+            default: false,
+        },
+    ))
+}
+
+/// Enhances `image` once. The result grows by one pixel on each side, everything further out
+/// only depends on the default of the old image.
+pub fn enhance_image(image: Image, index: &[bool]) -> Image {
+    let pixels = Grid::from_fn(
+        image.pixels.width() + 2,
+        image.pixels.height() + 2,
+        |(row, col)| {
+            let mut n: usize = 0;
+
+            for d_row in -2..=0 {
+                for d_col in -2..=0 {
+                    n = (n << 1) | image.get(row, col, (d_row, d_col)) as usize;
+                }
+            }
+
+            index[n]
+        },
+    );
+
+    // rest of universe is full of # => look up last index to find new default
+    let default = if image.default {
+        index[index.len() - 1]
+    // rest of universe is full of .
+    } else {
+        index[0]
+    };
+
+    Image { pixels, default }
+}
+impl Solution for Day20 {
+    type Input = (Vec<bool>, Image);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1((index, image): &Self::Input) -> usize {
+        (0..2)
+            .fold(image.clone(), |image, _| enhance_image(image, index))
+            .count_lights()
+    }
+
+    fn part_2((index, image): &Self::Input) -> usize {
+        (0..50)
+            .fold(image.clone(), |image, _| enhance_image(image, index))
+            .count_lights()
+    }
+}
@@ -1,9 +1,15 @@
+use itertools::Itertools;
 use std::collections::HashMap;
 
+use crate::parse::{self, ParseError};
+use crate::Solution;
+
+pub struct Day21;
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct Player {
-    pos: i32,
-    score: i32,
+pub struct Player {
+    pub pos: i32,
+    pub score: i32,
 }
 
 struct DeterministicDie {
@@ -33,43 +39,54 @@ impl DeterministicDie {
     }
 }
 
-fn main() {
-    let mut die = DeterministicDie {
-        next_roll: 1,
-        min: 1,
-        max: 100,
-        rolls: 0,
-    };
-
-    // Player 1 starting position: 7
-    // Player 2 starting position: 1
-    let mut player1 = Player { pos: 7, score: 0 };
-    let mut player2 = Player { pos: 1, score: 0 };
-
-    loop {
-        turn_1(&mut die, &mut player1);
-        if player1.score >= 1000 {
-            break;
-        }
-        turn_1(&mut die, &mut player2);
-        if player2.score >= 1000 {
-            break;
-        }
+impl Solution for Day21 {
+    type Input = (Player, Player);
+    type Output1 = i32;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let players = parse::parse_lines(input, |l| {
+            let (_, pos) = parse::split_once(l, l, " starting position: ")?;
+
+            Ok(Player {
+                pos: parse::number(l, pos)?,
+                score: 0,
+            })
+        })?;
+
+        players.into_iter().collect_tuple().ok_or_else(|| {
+            ParseError::end_of_line("", "expected exactly two players")
+                .on_line(input.lines().count())
+        })
     }
 
-    println!(
-        "Part 1: {}",
+    fn part_1(&(mut player1, mut player2): &Self::Input) -> i32 {
+        let mut die = DeterministicDie {
+            next_roll: 1,
+            min: 1,
+            max: 100,
+            rolls: 0,
+        };
+
+        loop {
+            turn_1(&mut die, &mut player1);
+            if player1.score >= 1000 {
+                break;
+            }
+            turn_1(&mut die, &mut player2);
+            if player2.score >= 1000 {
+                break;
+            }
+        }
+
         die.rolls() as i32 * std::cmp::min(player1.score, player2.score)
-    );
+    }
 
-    let player1 = Player { pos: 7, score: 0 };
-    let player2 = Player { pos: 1, score: 0 };
-    let (player1_universes, player2_universes) = solve_2(player1, player2, &mut HashMap::new());
+    fn part_2(&(player1, player2): &Self::Input) -> u64 {
+        let (player1_universes, player2_universes) = solve_2(player1, player2, &mut HashMap::new());
 
-    println!(
-        "Part 2: {:?}",
         std::cmp::max(player1_universes, player2_universes)
-    );
+    }
 }
 
 fn turn_1(die: &mut DeterministicDie, player: &mut Player) {
@@ -78,7 +95,7 @@ fn turn_1(die: &mut DeterministicDie, player: &mut Player) {
     player.score += player.pos;
 }
 
-fn solve_2(
+pub fn solve_2(
     player1: Player,
     player2: Player,
     cache: &mut HashMap<(Player, Player), (u64, u64)>,
@@ -0,0 +1,1361 @@
+use std::cell::Cell;
+
+use crate::parse::ParseError;
+use crate::search::{self, SearchProblem};
+use crate::{Answer, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+    type Output1 = Answer<usize>;
+    type Output2 = Answer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_burrow(input)
+    }
+
+    fn part_1(burrow: &Self::Input) -> Answer<usize> {
+        organize(burrow)
+    }
+
+    fn part_2(burrow: &Self::Input) -> Answer<usize> {
+        if burrow.layout.rooms.len() != FOLDED_ROWS[0].len() {
+            return Answer::None(format!(
+                "only burrows with {} rooms can be unfolded",
+                FOLDED_ROWS[0].len()
+            ));
+        }
+
+        match burrow.unfold(&FOLDED_ROWS) {
+            Ok(unfolded) => organize(&unfolded),
+            Err(e) => Answer::None(format!("the burrow can't be unfolded, {}", e.message)),
+        }
+    }
+}
+
+fn organize(burrow: &Burrow) -> Answer<usize> {
+    match find_shortest_path(burrow) {
+        Some(energy) => Answer::Found(energy),
+        None => Answer::None("the amphipods can't be organized".to_string()),
+    }
+}
+
+/// The type of an amphipod, `AmphipodType(0)` is `A`, `AmphipodType(1)` is `B` and so on. The
+/// room of each type is the one at the same position from the left.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct AmphipodType(pub u8);
+
+impl AmphipodType {
+    fn from_letter(c: char) -> Option<AmphipodType> {
+        c.is_ascii_uppercase().then(|| AmphipodType(c as u8 - b'A'))
+    }
+
+    pub fn letter(&self) -> char {
+        (b'A' + self.0) as char
+    }
+
+    fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// The shape of a burrow:
+///
+/// ```text
+/// #############
+/// #01234567890#  hallway of width 11
+/// ###A#B#C#D###  rooms above hallway cells 2, 4, 6 and 8
+///   #A#B#C#D#    room height 2
+///   #########
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layout {
+    pub hallway: usize,
+    /// The hallway cell above the room of each amphipod type.
+    pub rooms: Vec<usize>,
+    pub room_height: usize,
+    /// The energy each amphipod type needs per step.
+    pub costs: Vec<usize>,
+}
+
+impl Layout {
+    /// Every type of amphipod needs 10 times the energy of the one before, starting with 1. Past
+    /// 20 types that doesn't fit anymore, see `Burrow::with_costs`.
+    pub fn new(hallway: usize, rooms: Vec<usize>, room_height: usize) -> Self {
+        let costs = (0..rooms.len() as u32)
+            .map(|i| 10_usize.saturating_pow(i))
+            .collect();
+
+        Self {
+            hallway,
+            rooms,
+            room_height,
+            costs,
+        }
+    }
+
+    pub fn types(&self) -> impl Iterator<Item = AmphipodType> {
+        (0..self.rooms.len() as u8).map(AmphipodType)
+    }
+
+    fn cost(&self, kind: AmphipodType) -> usize {
+        self.costs[kind.index()]
+    }
+
+    fn index_above_room(&self, kind: AmphipodType) -> usize {
+        self.rooms[kind.index()]
+    }
+
+    fn is_above_room(&self, index: usize) -> bool {
+        self.rooms.contains(&index)
+    }
+
+    /// The bits per cell of a `Board`, enough for every type of amphipod and empty cells.
+    pub fn bits(&self) -> usize {
+        (usize::BITS - self.rooms.len().leading_zeros()) as usize
+    }
+
+    fn cells(&self) -> usize {
+        self.hallway + self.rooms.len() * self.room_height
+    }
+
+    /// The cell `slot` rows above the bottom of the room of `kind`.
+    fn room_cell(&self, kind: AmphipodType, slot: usize) -> usize {
+        self.hallway + kind.index() * self.room_height + slot
+    }
+}
+
+/// The rows between the first and second row of every room which are folded away in part 1, with
+/// the amphipod of every room from left to right.
+pub const FOLDED_ROWS: [&str; 2] = ["DCBA", "DBAC"];
+
+/// Parses one cell of a diagram, `.` is an empty cell. There are `types` types of amphipods.
+fn parse_cell(line: &str, index: usize, types: usize) -> Result<Option<AmphipodType>, ParseError> {
+    let expected = || {
+        format!(
+            "expected an amphipod from A to {} or '.'",
+            AmphipodType(types as u8 - 1).letter()
+        )
+    };
+
+    match line[index..].chars().next() {
+        Some('.') => Ok(None),
+        Some(c) => AmphipodType::from_letter(c)
+            .filter(|kind| kind.index() < types)
+            .map(Some)
+            .ok_or_else(|| ParseError::new(line, &line[index..index + c.len_utf8()], expected())),
+        None => Err(ParseError::end_of_line(line, expected())),
+    }
+}
+
+/// Parses a row of rooms like `  #B#A#D#C#`, returning the cell of every room.
+fn parse_room_row(line: &str, layout: &Layout) -> Result<Vec<Option<AmphipodType>>, ParseError> {
+    layout
+        .rooms
+        .iter()
+        .map(|index| {
+            if index + 1 >= line.len() {
+                return Err(ParseError::end_of_line(line, "expected a row of rooms"));
+            }
+            parse_cell(line, index + 1, layout.rooms.len())
+        })
+        .collect()
+}
+
+/// Parses a row for `Burrow::unfold`, returning the amphipod of every room.
+fn parse_folded_row(line: &str, layout: &Layout) -> Result<Vec<AmphipodType>, ParseError> {
+    let types = layout.rooms.len();
+    let mut row: Vec<AmphipodType> = vec![];
+
+    for (i, c) in line.char_indices() {
+        let token = &line[i..i + c.len_utf8()];
+        if row.len() == types {
+            return Err(ParseError::new(
+                line,
+                token,
+                format!("expected {} amphipods, one per room", types),
+            ));
+        }
+
+        let kind = match parse_cell(line, i, types)? {
+            Some(kind) if !row.contains(&kind) => kind,
+            Some(kind) => {
+                return Err(ParseError::new(
+                    line,
+                    token,
+                    format!("expected only one amphipod of type {}", kind.letter()),
+                ))
+            }
+            None => return Err(ParseError::new(line, token, "expected an amphipod")),
+        };
+        row.push(kind);
+    }
+
+    if row.len() < types {
+        return Err(ParseError::end_of_line(
+            line,
+            format!("expected {} amphipods, one per room", types),
+        ));
+    }
+
+    Ok(row)
+}
+
+/// Parses a diagram of the burrow. The hallway can have any width, the rooms are wherever the
+/// first row below the hallway isn't a wall and can have any depth:
+///
+/// ```text
+/// #############
+/// #...........#
+/// ###B#C#B#D###
+///   #A#D#C#A#
+///   #########
+/// ```
+///
+/// There's one type of amphipod per room, with as many amphipods of every type as there are cells
+/// in a room, and rooms are filled from the bottom.
+pub fn parse_burrow(input: &str) -> Result<Burrow, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let line = |i: usize| lines.get(i).copied().unwrap_or("");
+    let missing = |i: usize, message: &str| ParseError::end_of_line("", message).on_line(i + 1);
+    let error = |i: usize, j: usize, message: String| {
+        ParseError::new(line(i), &line(i)[j..j + 1], message).on_line(i + 1)
+    };
+
+    if !line(0).starts_with('#') {
+        return Err(missing(0, "expected the wall above the hallway"));
+    }
+
+    let hallway = line(1);
+    if !(hallway.len() >= 3 && hallway.starts_with('#') && hallway.ends_with('#')) {
+        return Err(ParseError::new(hallway, hallway, "expected a hallway").on_line(2));
+    }
+
+    let rooms: Vec<usize> = line(2)
+        .char_indices()
+        .filter(|(j, c)| *j > 0 && *j < hallway.len() - 1 && *c != '#' && *c != ' ')
+        .map(|(j, _)| j - 1)
+        .collect();
+
+    if rooms.is_empty() {
+        return Err(missing(2, "expected a row of rooms"));
+    }
+    if rooms.len() > 26 {
+        return Err(error(
+            2,
+            rooms[26] + 1,
+            "expected at most 26 rooms".to_string(),
+        ));
+    }
+
+    // The rows of the rooms, until the wall below them.
+    let room_rows = lines
+        .iter()
+        .skip(2)
+        .take_while(|l| l.trim().chars().any(|c| c != '#'))
+        .count();
+
+    if 2 + room_rows >= lines.len() {
+        return Err(missing(lines.len(), "expected the wall below the rooms"));
+    }
+
+    let layout = Layout::new(hallway.len() - 2, rooms, room_rows);
+
+    // Every cell as `(line, index in the line, cell)`, in reading order.
+    let mut cells = vec![];
+    let mut floor = vec![None; layout.hallway];
+
+    for (i, cell) in floor.iter_mut().enumerate() {
+        *cell = parse_cell(hallway, i + 1, layout.rooms.len()).map_err(|e| e.on_line(2))?;
+
+        if cell.is_some() && layout.is_above_room(i) {
+            return Err(error(1, i + 1, "expected '.' above a room".to_string()));
+        }
+        cells.push((1, i + 1, *cell));
+    }
+
+    let mut rows = vec![];
+
+    for i in 2..2 + room_rows {
+        let row = parse_room_row(line(i), &layout).map_err(|e| e.on_line(i + 1))?;
+
+        for (index, cell) in layout.rooms.iter().zip(&row) {
+            cells.push((i, index + 1, *cell));
+        }
+        rows.push(row);
+    }
+
+    for kind in layout.types() {
+        let mut of_kind = cells.iter().filter(|(_, _, cell)| *cell == Some(kind));
+
+        if let Some((i, j, _)) = of_kind.nth(room_rows) {
+            let message = format!(
+                "expected only {} amphipods of type {}",
+                room_rows,
+                kind.letter()
+            );
+            return Err(error(*i, *j, message));
+        }
+    }
+
+    // With no type having too many amphipods, one having too few means there's an empty cell
+    // in one of the rooms.
+    for kind in layout.types() {
+        if cells.iter().filter(|(_, _, c)| *c == Some(kind)).count() < room_rows {
+            let (i, j, _) = cells
+                .iter()
+                .find(|(i, _, c)| *i > 1 && c.is_none())
+                .unwrap();
+            let message = format!("expected {} amphipods of type {}", room_rows, kind.letter());
+            return Err(error(*i, *j, message));
+        }
+    }
+
+    if layout.cells() * layout.bits() > 128 {
+        return Err(missing(
+            lines.len(),
+            "expected a burrow which fits into 128 bits",
+        ));
+    }
+
+    let mut rooms = vec![vec![]; layout.rooms.len()];
+
+    // Rooms are stored bottom up, so go through the rows from the last one.
+    for (rows_below, row) in rows.iter().rev().enumerate() {
+        for ((room, cell), index) in rooms.iter_mut().zip(row).zip(&layout.rooms) {
+            match cell {
+                Some(amphipod) if room.len() == rows_below => room.push(*amphipod),
+                Some(_) => {
+                    let message = "expected '.' above an empty cell".to_string();
+                    let i = 2 + room_rows - 1 - rows_below;
+                    return Err(error(i, index + 1, message));
+                }
+                None => {}
+            }
+        }
+    }
+
+    let start = Board::new(&layout, &rooms, &floor);
+
+    Ok(Burrow { layout, start })
+}
+
+/// Organizing the amphipods of `start` into their rooms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Burrow {
+    pub layout: Layout,
+    pub start: Board,
+}
+
+impl Burrow {
+    /// Uses different energy costs for every type of amphipod.
+    pub fn with_costs(self, costs: Vec<usize>) -> Self {
+        assert_eq!(costs.len(), self.layout.rooms.len(), "one cost per room");
+
+        Self {
+            layout: Layout {
+                costs,
+                ..self.layout
+            },
+            ..self
+        }
+    }
+
+    /// Inserts `rows` below the first row of the rooms. Every row lists the amphipod of each room
+    /// from left to right, like `DCBA`, with one amphipod of every type.
+    pub fn unfold(&self, rows: &[&str]) -> Result<Burrow, ParseError> {
+        let mut layout = self.layout.clone();
+        let mut rooms: Vec<_> = layout
+            .types()
+            .map(|k| self.start.room(&layout, k))
+            .collect();
+
+        // Inserting the last row first, every row ends up above the ones inserted before it.
+        for (i, line) in rows.iter().enumerate().rev() {
+            let row = parse_folded_row(line, &self.layout).map_err(|e| e.on_line(i + 1))?;
+
+            for (room, cell) in rooms.iter_mut().zip(row) {
+                let below_first_row = room.len().min(layout.room_height - 1);
+                room.insert(below_first_row, cell);
+            }
+            layout.room_height += 1;
+        }
+
+        if layout.cells() * layout.bits() > 128 {
+            let line = rows.last().copied().unwrap_or("");
+            return Err(ParseError::end_of_line(
+                line,
+                "expected a burrow which fits into 128 bits",
+            )
+            .on_line(rows.len()));
+        }
+
+        let floor: Vec<_> = (0..layout.hallway)
+            .map(|i| self.start.floor(&layout, i))
+            .collect();
+        let start = Board::new(&layout, &rooms, &floor);
+
+        Ok(Burrow { layout, start })
+    }
+
+    /// Draws `board` like the diagrams in the puzzle, see `parse_burrow`.
+    pub fn render(&self, board: &Board) -> String {
+        let layout = &self.layout;
+        let width = layout.hallway + 2;
+        let letter = |cell: Option<&AmphipodType>| cell.map_or('.', |kind| kind.letter());
+
+        // The rows below the first one only have walls right around the rooms.
+        let first = *layout.rooms.iter().min().unwrap();
+        let last = *layout.rooms.iter().max().unwrap();
+        let walls = first..=(last + 2).min(width - 1);
+
+        let mut lines = vec!["#".repeat(width)];
+        lines.push(format!(
+            "#{}#",
+            (0..layout.hallway)
+                .map(|i| letter(board.floor(layout, i).as_ref()))
+                .collect::<String>()
+        ));
+
+        for row in 0..layout.room_height {
+            let line = (0..width)
+                .map(|j| match layout.rooms.iter().position(|i| i + 1 == j) {
+                    Some(room) => {
+                        let cell = layout
+                            .room_cell(AmphipodType(room as u8), layout.room_height - 1 - row);
+                        letter(board.get(layout, cell).as_ref())
+                    }
+                    None if row == 0 || walls.contains(&j) => '#',
+                    None => ' ',
+                })
+                .collect::<String>();
+            lines.push(line.trim_end().to_string());
+        }
+
+        lines.push(
+            (0..width)
+                .map(|j| if walls.contains(&j) { '#' } else { ' ' })
+                .collect::<String>()
+                .trim_end()
+                .to_string(),
+        );
+
+        lines.join("\n")
+    }
+}
+
+impl SearchProblem for Burrow {
+    type State = Board;
+
+    fn start(&self) -> Board {
+        self.start
+    }
+
+    fn successors(&self, board: &Board) -> Vec<(Board, usize)> {
+        let mut result = board.move_to_hall(&self.layout);
+        result.extend(board.move_into_room(&self.layout));
+        result
+    }
+
+    fn is_goal(&self, board: &Board) -> bool {
+        board.is_solved(&self.layout)
+    }
+
+    // Every amphipod which isn't home yet needs to walk out of its room, over to the room of its
+    // type and down into it, ignoring all others in the way. Those leaving their own room need to
+    // step aside in the hallway and back.
+    fn heuristic(&self, board: &Board) -> usize {
+        let layout = &self.layout;
+        let mut energy = 0;
+
+        for kind in layout.types() {
+            let settled = board.settled(layout, kind);
+            let target = layout.index_above_room(kind);
+
+            // The ones moving in fill the room from `settled` up.
+            let moving_in = layout.room_height - settled;
+            energy += layout.cost(kind) * moving_in * (moving_in + 1) / 2;
+
+            for slot in settled..board.room_len(layout, kind) {
+                let amphipod = board.get(layout, layout.room_cell(kind, slot)).unwrap();
+                let sideways = match layout.index_above_room(amphipod) {
+                    other if other == target => 2,
+                    other => other.abs_diff(target),
+                };
+                energy += layout.cost(amphipod) * (layout.room_height - slot + sideways);
+            }
+        }
+
+        for i in 0..layout.hallway {
+            if let Some(amphipod) = board.floor(layout, i) {
+                energy += layout.cost(amphipod) * i.abs_diff(layout.index_above_room(amphipod));
+            }
+        }
+
+        energy
+    }
+}
+
+/// Searches `burrow` without the successors which are deadlocked, see `Board::is_deadlocked`.
+/// Those can never be organized, so this doesn't change the cheapest path.
+pub struct Pruning<'a> {
+    pub burrow: &'a Burrow,
+    pruned: Cell<usize>,
+}
+
+impl<'a> Pruning<'a> {
+    pub fn new(burrow: &'a Burrow) -> Self {
+        Self {
+            burrow,
+            pruned: Cell::new(0),
+        }
+    }
+
+    /// The number of successors left out so far.
+    pub fn pruned(&self) -> usize {
+        self.pruned.get()
+    }
+}
+
+impl SearchProblem for Pruning<'_> {
+    type State = Board;
+
+    fn start(&self) -> Board {
+        self.burrow.start()
+    }
+
+    fn successors(&self, board: &Board) -> Vec<(Board, usize)> {
+        let mut successors = self.burrow.successors(board);
+        let before = successors.len();
+
+        successors.retain(|(board, _)| !board.is_deadlocked(&self.burrow.layout));
+        self.pruned
+            .set(self.pruned.get() + before - successors.len());
+
+        successors
+    }
+
+    fn is_goal(&self, board: &Board) -> bool {
+        self.burrow.is_goal(board)
+    }
+
+    fn heuristic(&self, board: &Board) -> usize {
+        self.burrow.heuristic(board)
+    }
+}
+
+pub fn find_shortest_path(burrow: &Burrow) -> Option<usize> {
+    search::a_star(&Pruning::new(burrow)).map(|result| result.cost)
+}
+
+/// A cheapest way of organizing the amphipods, as every board along the way together with the
+/// energy of the move which led to it. The first board is the start, reached with no energy.
+pub fn find_moves(burrow: &Burrow) -> Option<Vec<(Board, usize)>> {
+    let path = search::a_star(&Pruning::new(burrow))?.path;
+    let mut moves = vec![(path[0], 0)];
+
+    for pair in path.windows(2) {
+        let energy = burrow
+            .successors(&pair[0])
+            .into_iter()
+            .filter(|(board, _)| *board == pair[1])
+            .map(|(_, energy)| energy)
+            .min()
+            .expect("every step of the path is a move");
+
+        moves.push((pair[1], energy));
+    }
+
+    Some(moves)
+}
+
+/// Renders every board of `moves` with the energy spent on the move to it and the total so far.
+pub fn render_moves(burrow: &Burrow, moves: &[(Board, usize)]) -> String {
+    let mut total = 0;
+
+    moves
+        .iter()
+        .enumerate()
+        .map(|(i, (board, energy))| {
+            total += energy;
+            let title = match i {
+                0 => "Start:".to_string(),
+                i => format!("Move {}: {} energy, {} in total", i, energy, total),
+            };
+
+            format!("{}\n{}", title, burrow.render(board))
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Every cell of a burrow packed into one number with `Layout::bits` bits per cell, the hallway
+/// from the left first, then every room from the bottom up. Empty cells are 0, amphipods are 1
+/// plus their type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Board(u128);
+
+fn clamp_range(min: usize, max: usize) -> std::ops::Range<usize> {
+    if min <= max {
+        min..max
+    } else {
+        min..min
+    }
+}
+
+impl Board {
+    /// Packs the amphipods in every room from the bottom up, and those in the hallway.
+    pub fn new(
+        layout: &Layout,
+        rooms: &[Vec<AmphipodType>],
+        floor: &[Option<AmphipodType>],
+    ) -> Board {
+        let mut board = Board::default();
+
+        for (i, cell) in floor.iter().enumerate() {
+            board.set(layout, i, *cell);
+        }
+        for (kind, room) in layout.types().zip(rooms) {
+            for (slot, amphipod) in room.iter().enumerate() {
+                board.set(layout, layout.room_cell(kind, slot), Some(*amphipod));
+            }
+        }
+
+        board
+    }
+
+    fn get(&self, layout: &Layout, cell: usize) -> Option<AmphipodType> {
+        let bits = layout.bits();
+        let value = (self.0 >> (cell * bits)) & ((1 << bits) - 1);
+
+        (value != 0).then(|| AmphipodType(value as u8 - 1))
+    }
+
+    fn set(&mut self, layout: &Layout, cell: usize, amphipod: Option<AmphipodType>) {
+        let bits = layout.bits();
+        let value = amphipod.map_or(0, |kind| kind.0 as u128 + 1);
+
+        self.0 = self.0 & !(((1 << bits) - 1) << (cell * bits)) | value << (cell * bits);
+    }
+
+    pub fn floor(&self, layout: &Layout, index: usize) -> Option<AmphipodType> {
+        self.get(layout, index)
+    }
+
+    /// The amphipods in the room of `kind` from the bottom up.
+    pub fn room(&self, layout: &Layout, kind: AmphipodType) -> Vec<AmphipodType> {
+        (0..layout.room_height)
+            .map_while(|slot| self.get(layout, layout.room_cell(kind, slot)))
+            .collect()
+    }
+
+    fn room_len(&self, layout: &Layout, kind: AmphipodType) -> usize {
+        (0..layout.room_height)
+            .take_while(|slot| self.get(layout, layout.room_cell(kind, *slot)).is_some())
+            .count()
+    }
+
+    /// The number of amphipods at the bottom of the room of `kind` which are of that type.
+    fn settled(&self, layout: &Layout, kind: AmphipodType) -> usize {
+        (0..layout.room_height)
+            .take_while(|slot| self.get(layout, layout.room_cell(kind, *slot)) == Some(kind))
+            .count()
+    }
+
+    fn is_floor_empty(&self, layout: &Layout) -> bool {
+        (0..layout.hallway).all(|i| self.floor(layout, i).is_none())
+    }
+
+    fn is_room_correct(&self, layout: &Layout, kind: AmphipodType) -> bool {
+        self.settled(layout, kind) == self.room_len(layout, kind)
+    }
+
+    fn is_solved(&self, layout: &Layout) -> bool {
+        self.is_floor_empty(layout)
+            && layout
+                .types()
+                .all(|kind| self.settled(layout, kind) == layout.room_height)
+    }
+
+    /// Whether two amphipods in the hallway are in each other's way for good, with the left one
+    /// having to get past the right one to reach its room and the other way around. Amphipods
+    /// only leave the hallway into their room, so neither of them can ever move again.
+    pub fn is_deadlocked(&self, layout: &Layout) -> bool {
+        let target = |i| {
+            self.floor(layout, i)
+                .map(|kind| layout.index_above_room(kind))
+        };
+
+        (0..layout.hallway).any(|right| match target(right) {
+            // Only the amphipods between the right one and its room are in its way.
+            Some(right_target) if right_target < right => {
+                (right_target + 1..right).any(|left| matches!(target(left), Some(t) if t > right))
+            }
+            _ => false,
+        })
+    }
+
+    fn move_to_hall(&self, layout: &Layout) -> Vec<(Board, usize)> {
+        let mut result = vec![];
+
+        // Go through each room and move top amphipod into hallway. Unless entire room is full of
+        // that amphipod's type.
+        for target in layout.types() {
+            // We don't want to remove amphipods from a room if they are already in their correct
+            // room.
+            if self.is_room_correct(layout, target) {
+                continue;
+            }
+
+            let index_above_room = layout.index_above_room(target);
+            // Find all indices left and right of the spot above the room until the spot is no
+            // longer empty.
+            let floor_targets = (0..index_above_room)
+                .rev()
+                .take_while(|i| self.floor(layout, *i).is_none())
+                .chain(
+                    (index_above_room + 1..layout.hallway)
+                        .take_while(|i| self.floor(layout, *i).is_none()),
+                )
+                .filter(|i| !layout.is_above_room(*i))
+                .collect::<Vec<_>>();
+
+            // SAFETY: Can't be empty, since an empty room is considered "correct" and would lead
+            // to a skip earlier.
+            let top = layout.room_cell(target, self.room_len(layout, target) - 1);
+            let amphipod = self.get(layout, top).unwrap();
+            let cost_room = layout.room_height - self.room_len(layout, target) + 1;
+
+            let mut without = *self;
+            without.set(layout, top, None);
+
+            for floor_index in floor_targets {
+                let mut new_state = without;
+                let cost_floor = index_above_room.abs_diff(floor_index);
+
+                new_state.set(layout, floor_index, Some(amphipod));
+
+                result.push((new_state, (cost_room + cost_floor) * layout.cost(amphipod)));
+            }
+        }
+
+        result
+    }
+
+    fn move_into_room(&self, layout: &Layout) -> Vec<(Board, usize)> {
+        let mut result = vec![];
+
+        for i in 0..layout.hallway {
+            if let Some(kind) = self.floor(layout, i) {
+                if self.is_room_correct(layout, kind) {
+                    let index_above_room = layout.index_above_room(kind);
+                    if clamp_range(index_above_room, i)
+                        .chain(clamp_range(i + 1, index_above_room))
+                        .all(|j| self.floor(layout, j).is_none())
+                    {
+                        let mut new_state = *self;
+                        let len = self.room_len(layout, kind);
+
+                        new_state.set(layout, i, None);
+                        new_state.set(layout, layout.room_cell(kind, len), Some(kind));
+
+                        let distance_floor = i.abs_diff(index_above_room);
+                        let distance_room = layout.room_height - len;
+
+                        result.push((
+                            new_state,
+                            (distance_floor + distance_room) * layout.cost(kind),
+                        ));
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: AmphipodType = AmphipodType(0);
+    const B: AmphipodType = AmphipodType(1);
+    const C: AmphipodType = AmphipodType(2);
+    const D: AmphipodType = AmphipodType(3);
+
+    /// The layout of the puzzle, with rooms of the given height.
+    fn layout(room_height: usize) -> Layout {
+        Layout::new(11, vec![2, 4, 6, 8], room_height)
+    }
+
+    #[test]
+    fn test_move_into_room_empty_room() {
+        let board: Board = Board::new(
+            &layout(4),
+            &[vec![], vec![D, B, C, C], vec![C, A, B, B], vec![A, C, A, D]],
+            &[
+                Some(A),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
+        );
+
+        let board_expected: Board = Board::new(
+            &layout(4),
+            &[
+                vec![A],
+                vec![D, B, C, C],
+                vec![C, A, B, B],
+                vec![A, C, A, D],
+            ],
+            &[
+                None, None, None, None, None, None, None, None, None, None, None,
+            ],
+        );
+
+        assert_eq!(board.move_into_room(&layout(4)), vec![(board_expected, 6)]);
+    }
+
+    #[test]
+    fn test_move_into_room_full_of_as() {
+        let board: Board = Board::new(
+            &layout(4),
+            &[
+                vec![A, A],
+                vec![D, B, C, C],
+                vec![C, A, B, B],
+                vec![A, C, A, D],
+            ],
+            &[
+                Some(A),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
+        );
+
+        let board_expected: Board = Board::new(
+            &layout(4),
+            &[
+                vec![A, A, A],
+                vec![D, B, C, C],
+                vec![C, A, B, B],
+                vec![A, C, A, D],
+            ],
+            &[
+                None, None, None, None, None, None, None, None, None, None, None,
+            ],
+        );
+
+        assert_eq!(board.move_into_room(&layout(4)), vec![(board_expected, 4)]);
+    }
+
+    #[test]
+    fn test_move_into_room_blocked() {
+        let board: Board = Board::new(
+            &layout(4),
+            &[vec![], vec![D, B, C, C], vec![C], vec![A, C, A, D]],
+            &[
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(C),
+                Some(A),
+            ],
+        );
+
+        let board_expected: Board = Board::new(
+            &layout(4),
+            &[vec![], vec![D, B, C, C], vec![C, C], vec![A, C, A, D]],
+            &[
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(A),
+            ],
+        );
+
+        assert_eq!(
+            board.move_into_room(&layout(4)),
+            vec![(board_expected, 600)]
+        );
+    }
+
+    #[test]
+    fn test_move_into_room_multiple() {
+        let board: Board = Board::new(
+            &layout(4),
+            &[vec![], vec![D, B, C, C], vec![], vec![A, C, A, D]],
+            &[
+                Some(A),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(C),
+            ],
+        );
+
+        let board_expected_1: Board = Board::new(
+            &layout(4),
+            &[vec![A], vec![D, B, C, C], vec![], vec![A, C, A, D]],
+            &[
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(C),
+            ],
+        );
+
+        let board_expected_2: Board = Board::new(
+            &layout(4),
+            &[vec![], vec![D, B, C, C], vec![C], vec![A, C, A, D]],
+            &[
+                Some(A),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
+        );
+
+        assert_eq!(
+            board.move_into_room(&layout(4)),
+            vec![(board_expected_1, 6), (board_expected_2, 800)]
+        );
+    }
+
+    #[test]
+    fn test_move_to_hall() {
+        // #############
+        // #A....B....D#
+        // ###.#.#C#.###
+        //   #.#C#A#.#
+        //   #A#B#C#.#
+        //   #A#D#A#.#
+        //   #########
+        let board: Board = Board::new(
+            &layout(4),
+            &[vec![A, A], vec![D, B, C], vec![A, C, A, C], vec![]],
+            &[
+                Some(A),
+                None,
+                None,
+                None,
+                None,
+                Some(B),
+                None,
+                None,
+                None,
+                None,
+                Some(D),
+            ],
+        );
+
+        let board_expected_1: Board = Board::new(
+            &layout(4),
+            &[vec![A, A], vec![D, B], vec![A, C, A, C], vec![]],
+            &[
+                Some(A),
+                None,
+                None,
+                Some(C),
+                None,
+                Some(B),
+                None,
+                None,
+                None,
+                None,
+                Some(D),
+            ],
+        );
+
+        let board_expected_2: Board = Board::new(
+            &layout(4),
+            &[vec![A, A], vec![D, B], vec![A, C, A, C], vec![]],
+            &[
+                Some(A),
+                Some(C),
+                None,
+                None,
+                None,
+                Some(B),
+                None,
+                None,
+                None,
+                None,
+                Some(D),
+            ],
+        );
+
+        let board_expected_3: Board = Board::new(
+            &layout(4),
+            &[vec![A, A], vec![D, B, C], vec![A, C, A], vec![]],
+            &[
+                Some(A),
+                None,
+                None,
+                None,
+                None,
+                Some(B),
+                None,
+                Some(C),
+                None,
+                None,
+                Some(D),
+            ],
+        );
+        let board_expected_4: Board = Board::new(
+            &layout(4),
+            &[vec![A, A], vec![D, B, C], vec![A, C, A], vec![]],
+            &[
+                Some(A),
+                None,
+                None,
+                None,
+                None,
+                Some(B),
+                None,
+                None,
+                None,
+                Some(C),
+                Some(D),
+            ],
+        );
+
+        let result = board.move_to_hall(&layout(4));
+        assert_eq!(result.len(), 4);
+        assert_eq!(result[0], (board_expected_1, 300));
+        assert_eq!(result[1], (board_expected_2, 500));
+        assert_eq!(result[2], (board_expected_3, 200));
+        assert_eq!(result[3], (board_expected_4, 400));
+    }
+
+    const EXAMPLE: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn test_parse_burrow() {
+        let burrow = parse_burrow(EXAMPLE).unwrap();
+
+        assert_eq!(burrow.layout, layout(2));
+        assert_eq!(
+            burrow.start,
+            Board::new(
+                &layout(2),
+                &[vec![A, B], vec![D, C], vec![C, B], vec![A, D]],
+                &[None; 11]
+            )
+        );
+
+        let unfolded = burrow.unfold(&FOLDED_ROWS).unwrap();
+        assert_eq!(unfolded.layout, layout(4));
+        assert_eq!(
+            unfolded
+                .layout
+                .types()
+                .map(|kind| unfolded.start.room(&unfolded.layout, kind))
+                .collect::<Vec<_>>(),
+            vec![
+                vec![A, D, D, B],
+                vec![D, B, C, C],
+                vec![C, A, B, B],
+                vec![A, C, A, D]
+            ]
+        );
+        assert_eq!(find_shortest_path(&burrow), Some(12521));
+
+        // Part way through, with an amphipod in the hallway and a deeper room.
+        let burrow = parse_burrow(
+            "#############\n#.....D.....#\n###B#C#B#.###\n  #A#D#C#A#\n  #A#B#C#D#\n  #########",
+        )
+        .unwrap();
+        assert_eq!(burrow.start.floor(&burrow.layout, 5), Some(D));
+        assert_eq!(burrow.start.room(&burrow.layout, D), vec![D, A]);
+        assert_eq!(burrow.layout.room_height, 3);
+    }
+
+    /// Five rooms, one of them right at the end of the hallway.
+    const FIVE_ROOMS: &str =
+        "##############\n#............#\n###B#A#C#E##D#\n  #A#B#C#D##E#\n  ############";
+
+    #[test]
+    fn test_other_layouts() {
+        let burrow = parse_burrow(FIVE_ROOMS).unwrap();
+
+        assert_eq!(
+            burrow.layout,
+            Layout {
+                hallway: 12,
+                rooms: vec![2, 4, 6, 8, 11],
+                room_height: 2,
+                costs: vec![1, 10, 100, 1000, 10000],
+            }
+        );
+        assert_eq!(
+            parse_burrow(&burrow.render(&burrow.start)),
+            Ok(burrow.clone())
+        );
+
+        // A steps aside (2), B moves over (4) and A goes home (4). D has to wait left of its
+        // room (5) for E to pass (5), then goes back (2).
+        assert_eq!(
+            find_shortest_path(&burrow),
+            Some((2 + 4) + 4 * 10 + (5 + 2) * 1000 + 5 * 10000)
+        );
+
+        // With E being cheap, it's better for E to step aside (2) and let D pass (5).
+        let cheap_e = burrow.with_costs(vec![1, 10, 100, 1000, 1]);
+        assert_eq!(
+            find_shortest_path(&cheap_e),
+            Some((2 + 4) + 4 * 10 + 5 * 1000 + (2 + 5))
+        );
+
+        let error = parse_burrow("#####\n#...#\n###F#\n  #F#\n  ###").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message),
+            (3, 4, "expected an amphipod from A to A or '.'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_solve_other_layouts() {
+        let answers = |input: &str| {
+            let burrow = Day23::parse(input).unwrap();
+            (
+                Day23::part_1(&burrow).to_string(),
+                Day23::part_2(&burrow).to_string(),
+            )
+        };
+
+        assert_eq!(
+            answers(FIVE_ROOMS),
+            (
+                "57046".to_string(),
+                "none, only burrows with 4 rooms can be unfolded".to_string()
+            )
+        );
+        // The rows are unfolded into the rooms wherever they are. The extra cell at either end
+        // of the hallway makes part 2 cheaper than in the puzzle.
+        let shifted =
+            "###############\n#.............#\n####B#C#B#D####\n   #A#D#C#A#\n   #########";
+        let unfolded = parse_burrow(shifted).unwrap().unfold(&FOLDED_ROWS).unwrap();
+        assert_eq!(
+            unfolded
+                .layout
+                .types()
+                .map(|kind| unfolded.start.room(&unfolded.layout, kind))
+                .collect::<Vec<_>>(),
+            vec![
+                vec![A, D, D, B],
+                vec![D, B, C, C],
+                vec![C, A, B, B],
+                vec![A, C, A, D]
+            ]
+        );
+        assert_eq!(answers(shifted), ("12521".to_string(), "40273".to_string()));
+        // Amphipods can't stop in front of either room, so there's only one cell to step aside.
+        assert_eq!(
+            answers("#####\n#...#\n#BA##\n#AB#\n####"),
+            (
+                "none, the amphipods can't be organized".to_string(),
+                "none, only burrows with 4 rooms can be unfolded".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_unfold_errors() {
+        let burrow = parse_burrow(EXAMPLE).unwrap();
+        let error = |rows: &[&str]| {
+            let e = burrow.unfold(rows).unwrap_err();
+            (e.line, e.column, e.message)
+        };
+
+        assert_eq!(
+            error(&["DCBA", "DBA"]),
+            (2, 4, "expected 4 amphipods, one per room".into())
+        );
+        assert_eq!(
+            error(&["DCBAD"]),
+            (1, 5, "expected 4 amphipods, one per room".into())
+        );
+        assert_eq!(
+            error(&["DCBB"]),
+            (1, 4, "expected only one amphipod of type B".into())
+        );
+        assert_eq!(error(&["DC.A"]), (1, 3, "expected an amphipod".into()));
+        assert_eq!(
+            error(&["DCBE"]),
+            (1, 4, "expected an amphipod from A to D or '.'".into())
+        );
+    }
+
+    #[test]
+    fn test_find_moves() {
+        let burrow = parse_burrow(EXAMPLE).unwrap();
+        let moves = find_moves(&burrow).unwrap();
+
+        assert_eq!(moves.iter().map(|(_, energy)| energy).sum::<usize>(), 12521);
+        assert_eq!(burrow.render(&moves[0].0), EXAMPLE);
+        assert!(moves.last().unwrap().0.is_solved(&burrow.layout));
+
+        // Every move takes one amphipod from a room to the hallway or the other way around.
+        for pair in moves.windows(2) {
+            let (before, after, layout) = (&pair[0].0, &pair[1].0, &burrow.layout);
+            let moved_floor = (0..11)
+                .filter(|i| before.floor(layout, *i) != after.floor(layout, *i))
+                .count();
+            let moved_rooms = layout
+                .types()
+                .filter(|kind| before.room(layout, *kind) != after.room(layout, *kind))
+                .count();
+            assert_eq!((moved_floor, moved_rooms), (1, 1));
+        }
+
+        let rendered = render_moves(&burrow, &moves[..2]);
+        assert!(rendered.starts_with("Start:\n#############\n"));
+        assert!(rendered.contains(&format!(
+            "\n\nMove 1: {} energy, {} in total\n",
+            moves[1].1, moves[1].1
+        )));
+    }
+
+    #[test]
+    fn test_heuristic() {
+        let burrow = parse_burrow(EXAMPLE).unwrap();
+
+        for (burrow, energy) in [
+            (burrow.unfold(&FOLDED_ROWS).unwrap(), 44169),
+            (burrow, 12521),
+        ] {
+            let moves = find_moves(&burrow).unwrap();
+            let energies: Vec<usize> = moves.iter().map(|(_, energy)| *energy).collect();
+
+            assert_eq!(energies.iter().sum::<usize>(), energy);
+
+            // It never overestimates the energy still needed along the cheapest path.
+            for (i, (board, _)) in moves.iter().enumerate() {
+                assert!(burrow.heuristic(board) <= energies[i + 1..].iter().sum());
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_deadlocked() {
+        let hallway = |cells: &str| {
+            let floor: Vec<Option<AmphipodType>> =
+                cells.chars().map(AmphipodType::from_letter).collect();
+            Board::new(&layout(2), &[vec![], vec![], vec![], vec![]], &floor)
+        };
+
+        // D has to get past A on the right and A past D on the left.
+        assert!(hallway("...D.A.....").is_deadlocked(&layout(2)));
+        assert!(hallway(".....D.B...").is_deadlocked(&layout(2)));
+        // Either of them can go home first.
+        assert!(!hallway("...A.D.....").is_deadlocked(&layout(2)));
+        assert!(!hallway("...D.....A.").is_deadlocked(&layout(2)));
+        assert!(!hallway(".B...C.....").is_deadlocked(&layout(2)));
+        // Only A and C are in each other's way.
+        assert!(hallway("B..C.D.A...").is_deadlocked(&layout(2)));
+    }
+
+    #[test]
+    fn test_pruning() {
+        let burrow = parse_burrow(EXAMPLE).unwrap();
+
+        for (burrow, energy) in [
+            (burrow.unfold(&FOLDED_ROWS).unwrap(), 44169),
+            (burrow, 12521),
+        ] {
+            let pruning = Pruning::new(&burrow);
+
+            assert_eq!(search::a_star(&pruning).unwrap().cost, energy);
+            assert!(pruning.pruned() > 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_burrow_errors() {
+        let error = |input: &str| {
+            let e = parse_burrow(input).unwrap_err();
+            (e.line, e.column, e.message)
+        };
+
+        assert_eq!(
+            error(&EXAMPLE.replace("#A#D", "#E#D")),
+            (4, 4, "expected an amphipod from A to D or '.'".to_string())
+        );
+        assert_eq!(
+            error(&EXAMPLE.replace("#A#D", "#B#D")),
+            (4, 4, "expected only 2 amphipods of type B".to_string())
+        );
+        assert_eq!(
+            error(&EXAMPLE.replace("#A#D", "#.#D")),
+            (4, 4, "expected 2 amphipods of type A".to_string())
+        );
+        assert_eq!(
+            error(&EXAMPLE.replace("#A#D", "#.#D").replace("#...", "#A..")),
+            (3, 4, "expected '.' above an empty cell".to_string())
+        );
+        assert_eq!(
+            error(&EXAMPLE.replace("#...", "#..A").replace("###B", "###.")),
+            (2, 4, "expected '.' above a room".to_string())
+        );
+        assert_eq!(
+            error("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#"),
+            (5, 1, "expected the wall below the rooms".to_string())
+        );
+    }
+}
@@ -0,0 +1,1822 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::parse::{self, ParseError};
+use crate::{Answer, Solution};
+
+pub struct Day24;
+
+#[derive(Debug)]
+pub enum VarOrConst {
+    Const(i64),
+    Var(char),
+}
+
+impl VarOrConst {
+    fn get(&self, registers: &Registers) -> i64 {
+        match self {
+            Self::Const(val) => *val,
+            Self::Var(var) => registers.get(*var),
+        }
+    }
+
+    pub fn pretty_print(&self) -> String {
+        match self {
+            Self::Var(var) => var.to_string(),
+            Self::Const(val) => val.to_string(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Inp(char),
+    Add(char, VarOrConst),
+    Mod(char, VarOrConst),
+    Div(char, VarOrConst),
+    Mul(char, VarOrConst),
+    Eql(char, VarOrConst),
+}
+
+/// Formats the instruction as it's written in the puzzle input, like `add x -3`.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Instruction::*;
+
+        let (name, var, val) = match self {
+            Inp(var) => return write!(f, "inp {}", var),
+            Add(var, val) => ("add", var, val),
+            Mod(var, val) => ("mod", var, val),
+            Div(var, val) => ("div", var, val),
+            Mul(var, val) => ("mul", var, val),
+            Eql(var, val) => ("eql", var, val),
+        };
+
+        write!(f, "{} {} {}", name, var, val.pretty_print())
+    }
+}
+
+impl Instruction {
+    /// The register the instruction writes.
+    pub fn var(&self) -> char {
+        match self {
+            Self::Inp(var)
+            | Self::Add(var, _)
+            | Self::Mod(var, _)
+            | Self::Div(var, _)
+            | Self::Mul(var, _)
+            | Self::Eql(var, _) => *var,
+        }
+    }
+
+    /// The operation and its second operand, for anything but `inp`.
+    pub fn operation(&self) -> Option<(Op, &VarOrConst)> {
+        match self {
+            Self::Inp(_) => None,
+            Self::Add(_, val) => Some((Op::Add, val)),
+            Self::Mod(_, val) => Some((Op::Mod, val)),
+            Self::Div(_, val) => Some((Op::Div, val)),
+            Self::Mul(_, val) => Some((Op::Mul, val)),
+            Self::Eql(_, val) => Some((Op::Eql, val)),
+        }
+    }
+
+    pub fn pretty_print(&self) -> String {
+        match self {
+            Self::Inp(var) => format!("inp {}", var),
+            Self::Add(var, val) => format!("{} = {} + {};", var, var, val.pretty_print()),
+            Self::Mod(var, val) => format!("{} = {} % {};", var, var, val.pretty_print()),
+            Self::Div(var, val) => format!("{} = {} / {};", var, var, val.pretty_print()),
+            Self::Mul(var, VarOrConst::Const(0)) => format!("{} = 0;", var),
+            Self::Mul(var, val) => format!("{} = {} * {};", var, var, val.pretty_print()),
+            Self::Eql(var, val) => format!("eql {} {}", var, val.pretty_print()),
+        }
+    }
+}
+
+/// The arithmetic of every instruction but `inp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+impl Op {
+    /// Computes `a op b` like the ALU, wrapping around on overflow.
+    pub fn apply(self, a: i64, b: i64) -> Result<i64, Fault> {
+        match (self, a, b) {
+            (Op::Add, a, b) => Ok(a.wrapping_add(b)),
+            (Op::Mul, a, b) => Ok(a.wrapping_mul(b)),
+            (Op::Div, _, 0) => Err(Fault::DivisionByZero),
+            (Op::Div, a, b) => Ok(a.wrapping_div(b)),
+            (Op::Mod, _, 0) => Err(Fault::ModuloByZero),
+            (Op::Mod, a, b) if a < 0 || b < 0 => Err(Fault::NegativeModulo),
+            (Op::Mod, a, b) => Ok(a % b),
+            (Op::Eql, a, b) => Ok((a == b) as i64),
+        }
+    }
+}
+
+fn parse_var(line: &str, input: &str) -> Result<char, ParseError> {
+    match input {
+        "x" | "y" | "z" | "w" => Ok(input.chars().next().unwrap()),
+        _ => Err(ParseError::new(line, input, "expected a variable")),
+    }
+}
+
+pub fn parse_var_or_const(line: &str, input: &str) -> Result<VarOrConst, ParseError> {
+    match input {
+        "x" | "y" | "z" | "w" => Ok(VarOrConst::Var(input.chars().next().unwrap())),
+        _ => input
+            .parse()
+            .map(VarOrConst::Const)
+            .map_err(|_| ParseError::new(line, input, "expected a variable or a number")),
+    }
+}
+
+pub fn parse_instruction(input: &str) -> Result<Instruction, ParseError> {
+    use Instruction::*;
+
+    let (cmd, rest) = parse::split_once(input, input, " ")?;
+
+    if cmd == "inp" {
+        return Ok(Inp(parse_var(input, rest)?));
+    }
+
+    let (left, right) = parse::split_once(input, rest, " ")?;
+
+    let left = parse_var(input, left)?;
+    let right = parse_var_or_const(input, right)?;
+
+    match cmd {
+        "mul" => Ok(Mul(left, right)),
+        "add" => Ok(Add(left, right)),
+        "div" => Ok(Div(left, right)),
+        "mod" => Ok(Mod(left, right)),
+        "eql" => Ok(Eql(left, right)),
+        _ => Err(ParseError::new(input, cmd, "unknown command")),
+    }
+}
+
+/// The values of `w`, `x`, `y` and `z`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Registers([i64; 4]);
+
+impl Registers {
+    fn index(var: char) -> usize {
+        match var {
+            'w' => 0,
+            'x' => 1,
+            'y' => 2,
+            'z' => 3,
+            _ => panic!("unknown register {}", var),
+        }
+    }
+
+    pub fn get(&self, var: char) -> i64 {
+        self.0[Self::index(var)]
+    }
+
+    pub fn set(&mut self, var: char, value: i64) {
+        self.0[Self::index(var)] = value;
+    }
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [w, x, y, z] = self.0;
+        write!(f, "w={} x={} y={} z={}", w, x, y, z)
+    }
+}
+
+/// Why the ALU stopped working.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    DivisionByZero,
+    ModuloByZero,
+    NegativeModulo,
+    MissingInput,
+}
+
+/// A fault of the instruction at index `pc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AluError {
+    pub pc: usize,
+    pub fault: Fault,
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let fault = match self.fault {
+            Fault::DivisionByZero => "division by zero",
+            Fault::ModuloByZero => "modulo by zero",
+            Fault::NegativeModulo => "modulo with a negative operand",
+            Fault::MissingInput => "no input left",
+        };
+        write!(f, "instruction {}: {}", self.pc, fault)
+    }
+}
+
+impl std::error::Error for AluError {}
+
+/// Why `Alu::run` returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction at this index is next and has a breakpoint.
+    Breakpoint(usize),
+    Finished,
+}
+
+/// Runs a program one instruction at a time, reading `inp` values from `input` in order.
+pub struct Alu<'a> {
+    program: &'a [Instruction],
+    input: Vec<i64>,
+    pc: usize,
+    registers: Registers,
+    breakpoints: HashSet<usize>,
+}
+
+impl<'a> Alu<'a> {
+    pub fn new(program: &'a [Instruction], input: &[i64]) -> Self {
+        Self {
+            program,
+            input: input.iter().rev().copied().collect(),
+            pc: 0,
+            registers: Registers::default(),
+            breakpoints: HashSet::new(),
+        }
+    }
+
+    /// Same as `new`, but starts with the given register values.
+    pub fn with_registers(program: &'a [Instruction], input: &[i64], registers: Registers) -> Self {
+        Self {
+            registers,
+            ..Self::new(program, input)
+        }
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    /// The index of the next instruction.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    pub fn add_breakpoint(&mut self, index: usize) {
+        self.breakpoints.insert(index);
+    }
+
+    pub fn remove_breakpoint(&mut self, index: usize) {
+        self.breakpoints.remove(&index);
+    }
+
+    /// Executes the next instruction, if there is one. Registers stay unchanged on errors.
+    pub fn step(&mut self) -> Result<(), AluError> {
+        let Some(instruction) = self.program.get(self.pc) else {
+            return Ok(());
+        };
+        let error = |fault| AluError { pc: self.pc, fault };
+        let var = instruction.var();
+
+        let value = match instruction.operation() {
+            None => self.input.pop().ok_or(error(Fault::MissingInput))?,
+            Some((op, val)) => {
+                let r = &self.registers;
+                op.apply(r.get(var), val.get(r)).map_err(error)?
+            }
+        };
+
+        self.registers.set(var, value);
+        self.pc += 1;
+
+        Ok(())
+    }
+
+    /// Runs until the end of the program or until the next instruction has a breakpoint. The
+    /// current instruction is always executed, so calling `run` again continues after a
+    /// breakpoint.
+    pub fn run(&mut self) -> Result<Stop, AluError> {
+        while !self.is_finished() {
+            self.step()?;
+
+            if self.breakpoints.contains(&self.pc) {
+                return Ok(Stop::Breakpoint(self.pc));
+            }
+        }
+
+        Ok(Stop::Finished)
+    }
+
+    /// Runs the rest of the program and returns the registers at the end of every `inp` block,
+    /// i.e. right before every `inp` but the first and at the end.
+    pub fn run_blocks(&mut self) -> Result<Vec<Registers>, AluError> {
+        let mut blocks = vec![];
+
+        while !self.is_finished() {
+            self.step()?;
+
+            if matches!(self.program.get(self.pc), Some(Instruction::Inp(_)) | None) {
+                blocks.push(self.registers);
+            }
+        }
+
+        Ok(blocks)
+    }
+}
+
+/// Formats the registers after each `inp` block, one block per line.
+pub fn dump_blocks(program: &[Instruction], input: &[i64]) -> Result<String, AluError> {
+    Ok(Alu::new(program, input)
+        .run_blocks()?
+        .iter()
+        .enumerate()
+        .map(|(i, registers)| format!("block {:2}: {}", i + 1, registers))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Runs the program with a model number (like `13579246899999`) as input and returns `z`.
+pub fn evaluate(input: &str, instructions: &[Instruction]) -> Result<i64, AluError> {
+    let digits: Vec<i64> = input
+        .chars()
+        .map(|c| c.to_digit(10).expect("model numbers only contain digits") as i64)
+        .collect();
+
+    let mut alu = Alu::new(instructions, &digits);
+    alu.run()?;
+
+    Ok(alu.registers().get('z'))
+}
+
+impl Solution for Day24 {
+    type Input = Vec<Instruction>;
+    type Output1 = Answer<i64>;
+    type Output2 = Answer<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::parse_lines(input, parse_instruction)
+    }
+
+    fn part_1(instructions: &Self::Input) -> Answer<i64> {
+        let max = solve_monad(instructions)
+            .and_then(|(max, _)| check_model_number(instructions, &max))
+            .or_else(|| {
+                let max = fallback_search(instructions).max()?;
+                check_model_number(instructions, &max)
+            });
+
+        model_number(max)
+    }
+
+    fn part_2(instructions: &Self::Input) -> Answer<i64> {
+        let min = solve_monad(instructions)
+            .and_then(|(_, min)| check_model_number(instructions, &min))
+            .or_else(|| {
+                let min = fallback_search(instructions).min()?;
+                check_model_number(instructions, &min)
+            });
+
+        model_number(min)
+    }
+}
+
+/// The answer for a model number, if any was accepted.
+fn model_number(number: Option<i64>) -> Answer<i64> {
+    match number {
+        Some(number) => Answer::Found(number),
+        None => Answer::None("no model number is accepted".to_string()),
+    }
+}
+
+/// The search for programs `solve_monad` can't solve. Pruning on `z` only holds for programs
+/// shaped like MONAD, any other program is searched without it.
+fn fallback_search(instructions: &[Instruction]) -> AluSearch<'_> {
+    let search = AluSearch::new(instructions);
+
+    if extract_blocks(instructions).is_some() {
+        search.with_z_pruning()
+    } else {
+        search
+    }
+}
+
+/// Runs `digits` through the ALU and returns them as a number if they're accepted.
+fn check_model_number(instructions: &[Instruction], digits: &[i64]) -> Option<i64> {
+    let number = digits.iter().fold(0, |n, d| n * 10 + d);
+
+    (evaluate(&number.to_string(), instructions) == Ok(0)).then_some(number)
+}
+
+/// Splits a program into blocks, each starting with an `inp`.
+pub fn split_blocks(program: &[Instruction]) -> Vec<&[Instruction]> {
+    let mut starts: Vec<usize> = program
+        .iter()
+        .enumerate()
+        .filter(|(_, i)| matches!(i, Instruction::Inp(_)))
+        .map(|(i, _)| i)
+        .collect();
+    starts.push(program.len());
+
+    starts.windows(2).map(|w| &program[w[0]..w[1]]).collect()
+}
+
+/// The constants of one block of MONAD, the only parts which differ between blocks. Each block
+/// reads a digit `w` and computes
+///
+/// ```text
+/// x = z % 26 + check
+/// z /= div
+/// if x != w { z = z * 26 + w + offset }
+/// ```
+///
+/// So `z` is a stack of base 26 digits: every block with `div == 1` pushes `w + offset` (its
+/// `check` is always above 9, so `x != w`), every block with `div == 26` pops a value and only
+/// avoids pushing again if `w` equals that value plus `check`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonadBlock {
+    pub div: i64,
+    pub check: i64,
+    pub offset: i64,
+}
+
+/// The instructions of every block of MONAD, `{}` stands for the constants of a block.
+const MONAD_BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z {}", "add x {}", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y {}",
+    "mul y x", "add z y",
+];
+
+fn extract_block(block: &[Instruction]) -> Option<MonadBlock> {
+    if block.len() != MONAD_BLOCK.len() {
+        return None;
+    }
+
+    let mut constants = vec![];
+
+    for (instruction, template) in block.iter().zip(MONAD_BLOCK) {
+        let source = instruction.to_string();
+
+        match template.strip_suffix("{}") {
+            Some(prefix) => constants.push(source.strip_prefix(prefix)?.parse::<i64>().ok()?),
+            None if source == template => {}
+            None => return None,
+        }
+    }
+
+    match constants[..] {
+        [div, check, offset] if div == 1 || div == 26 => Some(MonadBlock { div, check, offset }),
+        _ => None,
+    }
+}
+
+/// Extracts the constants of every block, returns `None` if the program isn't shaped like MONAD.
+pub fn extract_blocks(program: &[Instruction]) -> Option<Vec<MonadBlock>> {
+    if !matches!(program.first(), Some(Instruction::Inp(_))) {
+        return None;
+    }
+
+    split_blocks(program)
+        .into_iter()
+        .map(extract_block)
+        .collect()
+}
+
+/// Computes `z` of MONAD from its constants, much faster than running it on the ALU.
+pub fn evaluate_blocks(blocks: &[MonadBlock], digits: &[i64]) -> i64 {
+    let mut z = 0;
+
+    for (block, w) in blocks.iter().zip(digits) {
+        let x = z % 26 + block.check;
+
+        z /= block.div;
+
+        if x != *w {
+            z = z * 26 + w + block.offset;
+        }
+    }
+
+    z
+}
+
+/// The digit of block `right` has to be the one of block `left` plus `diff`, which is what's
+/// needed for `right` to pop the value `left` pushed without pushing a new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub left: usize,
+    pub right: usize,
+    pub diff: i64,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.diff {
+            0 => write!(f, "d[{}] == d[{}]", self.right, self.left),
+            diff if diff > 0 => write!(f, "d[{}] == d[{}] + {}", self.right, self.left, diff),
+            diff => write!(f, "d[{}] == d[{}] - {}", self.right, self.left, -diff),
+        }
+    }
+}
+
+/// Pairs up pushing and popping blocks. `z` can only end up as 0 if every value pushed is popped
+/// again, so this returns `None` if the pushes and pops don't match up.
+pub fn constraints(blocks: &[MonadBlock]) -> Option<Vec<Constraint>> {
+    let mut stack = vec![];
+    let mut result = vec![];
+
+    for (i, block) in blocks.iter().enumerate() {
+        if block.div == 1 {
+            if block.check <= 9 {
+                return None;
+            }
+            stack.push((i, block.offset));
+        } else {
+            let (left, offset) = stack.pop()?;
+            result.push(Constraint {
+                left,
+                right: i,
+                diff: offset + block.check,
+            });
+        }
+    }
+
+    stack.is_empty().then_some(result)
+}
+
+/// The largest and smallest digits (1 to 9) satisfying all `constraints`, or `None` if a
+/// constraint can't be satisfied.
+pub fn solve_constraints(constraints: &[Constraint], len: usize) -> Option<(Vec<i64>, Vec<i64>)> {
+    let mut max = vec![0; len];
+    let mut min = vec![0; len];
+
+    for c in constraints {
+        if c.diff.abs() > 8 {
+            return None;
+        }
+
+        max[c.left] = 9 - c.diff.max(0);
+        max[c.right] = max[c.left] + c.diff;
+        min[c.left] = 1 - c.diff.min(0);
+        min[c.right] = min[c.left] + c.diff;
+    }
+
+    Some((max, min))
+}
+
+/// The digits of the largest and smallest model numbers MONAD accepts. `None` if the program
+/// isn't shaped like MONAD, or if the constraints don't describe it, which happens when a pushed
+/// digit plus its offset doesn't stay below 26.
+pub fn solve_monad(program: &[Instruction]) -> Option<(Vec<i64>, Vec<i64>)> {
+    let blocks = extract_blocks(program)?;
+    let (max, min) = solve_constraints(&constraints(&blocks)?, blocks.len())?;
+
+    (evaluate_blocks(&blocks, &max) == 0 && evaluate_blocks(&blocks, &min) == 0)
+        .then_some((max, min))
+}
+
+/// The registers a block reads before writing them, and the registers it writes.
+fn reads_and_writes(block: &[Instruction]) -> ([bool; 4], [bool; 4]) {
+    use Instruction::*;
+
+    let mut live = [false; 4];
+    let mut written = [false; 4];
+
+    let mut read = |var: char, written: &[bool; 4]| {
+        let i = Registers::index(var);
+        live[i] |= !written[i];
+    };
+
+    for instruction in block {
+        let var = match instruction {
+            Inp(var) | Mul(var, VarOrConst::Const(0)) => *var,
+            Add(var, val) | Mod(var, val) | Div(var, val) | Mul(var, val) | Eql(var, val) => {
+                read(*var, &written);
+                if let VarOrConst::Var(other) = val {
+                    read(*other, &written);
+                }
+                *var
+            }
+        };
+        written[Registers::index(var)] = true;
+    }
+
+    (live, written)
+}
+
+/// The registers which are read before being written from each block on, including `z` which
+/// is checked at the end. Everything else can't affect the result, so these are the only
+/// registers which matter for memoization.
+fn live_registers(blocks: &[&[Instruction]]) -> Vec<[bool; 4]> {
+    let mut live = vec![[false; 4]; blocks.len() + 1];
+    live[blocks.len()][Registers::index('z')] = true;
+
+    for (i, block) in blocks.iter().enumerate().rev() {
+        let (read, written) = reads_and_writes(block);
+
+        for r in 0..4 {
+            live[i][r] = read[r] || (live[i + 1][r] && !written[r]);
+        }
+    }
+
+    live
+}
+
+/// Finds the largest and smallest inputs of single digits (1 to 9) for which any ALU program
+/// ends with `z == 0`. The program is run block by block, each block starting with an `inp`, and
+/// states which didn't lead to a solution are remembered as `(block, registers)`, keeping only the
+/// registers the rest of the program reads.
+pub struct AluSearch<'a> {
+    blocks: Vec<&'a [Instruction]>,
+    live: Vec<[bool; 4]>,
+    start: Result<Registers, AluError>,
+    z_limits: Option<Vec<i64>>,
+}
+
+impl<'a> AluSearch<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        // Whatever comes before the first `inp` only has to run once.
+        let first_input = program
+            .iter()
+            .position(|i| matches!(i, Instruction::Inp(_)))
+            .unwrap_or(program.len());
+        let mut alu = Alu::new(&program[..first_input], &[]);
+        let start = alu.run().map(|_| alu.registers());
+
+        let blocks = split_blocks(program);
+        let live = live_registers(&blocks);
+
+        Self {
+            blocks,
+            live,
+            start,
+            z_limits: None,
+        }
+    }
+
+    /// Gives up on states where `z` is too large to ever get back to 0, i.e. at least the product
+    /// of all divisors of the `div z` instructions still to come. This assumes that nothing else
+    /// makes `z` smaller, which holds for MONAD, but not for ALU programs in general.
+    pub fn with_z_pruning(self) -> Self {
+        let mut z_limits = vec![1_i64; self.blocks.len() + 1];
+
+        for (i, block) in self.blocks.iter().enumerate().rev() {
+            let divisor = block
+                .iter()
+                .map(|instruction| match instruction {
+                    Instruction::Div('z', VarOrConst::Const(d)) => d.abs().max(1),
+                    _ => 1,
+                })
+                .fold(1_i64, |product, d| product.saturating_mul(d));
+
+            z_limits[i] = z_limits[i + 1].saturating_mul(divisor);
+        }
+
+        Self {
+            z_limits: Some(z_limits),
+            ..self
+        }
+    }
+
+    pub fn max(&self) -> Option<Vec<i64>> {
+        self.search(&[9, 8, 7, 6, 5, 4, 3, 2, 1])
+    }
+
+    pub fn min(&self) -> Option<Vec<i64>> {
+        self.search(&[1, 2, 3, 4, 5, 6, 7, 8, 9])
+    }
+
+    /// Finds the first input that's accepted, trying the `digits` in order for every block.
+    fn search(&self, digits: &[i64]) -> Option<Vec<i64>> {
+        let mut failed = HashSet::new();
+        let mut input = vec![];
+
+        let start = self.start.ok()?;
+        self.search_from(0, start, digits, &mut failed, &mut input)
+            .then_some(input)
+    }
+
+    fn search_from(
+        &self,
+        block: usize,
+        registers: Registers,
+        digits: &[i64],
+        failed: &mut HashSet<(usize, Registers)>,
+        input: &mut Vec<i64>,
+    ) -> bool {
+        if block == self.blocks.len() {
+            return registers.get('z') == 0;
+        }
+
+        let mut state = Registers::default();
+        for (i, live) in self.live[block].iter().enumerate() {
+            if *live {
+                state.0[i] = registers.0[i];
+            }
+        }
+
+        if let Some(limits) = &self.z_limits {
+            if state.get('z').abs() >= limits[block] {
+                return false;
+            }
+        }
+
+        if failed.contains(&(block, state)) {
+            return false;
+        }
+
+        for digit in digits {
+            let mut alu = Alu::with_registers(self.blocks[block], &[*digit], state);
+
+            // Inputs which make the ALU fault are just as invalid as those leaving `z != 0`.
+            if alu.run().is_ok() {
+                input.push(*digit);
+                if self.search_from(block + 1, alu.registers(), digits, failed, input) {
+                    return true;
+                }
+                input.pop();
+            }
+        }
+
+        failed.insert((block, state));
+        false
+    }
+}
+
+/// A value in compiled code: a constant, the `n`th input or the current value of a register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Const(i64),
+    Input(usize),
+    Reg(char),
+}
+
+impl Operand {
+    fn reg(&self) -> Option<char> {
+        match self {
+            Self::Reg(var) => Some(*var),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Const(val) => write!(f, "{}", val),
+            Self::Input(n) => write!(f, "input[{}]", n),
+            Self::Reg(var) => write!(f, "{}", var),
+        }
+    }
+}
+
+/// A condition the compiled code checks before an operation which could fault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Guard {
+    /// The divisor of a `div`.
+    NonZero(Operand),
+    /// The dividend of a `mod`.
+    NonNegative(Operand),
+    /// The divisor of a `mod`.
+    Positive(Operand),
+}
+
+impl Guard {
+    fn operand(&self) -> Operand {
+        match self {
+            Self::NonZero(a) | Self::NonNegative(a) | Self::Positive(a) => *a,
+        }
+    }
+
+    fn holds(&self, value: i64) -> bool {
+        match self {
+            Self::NonZero(_) => value != 0,
+            Self::NonNegative(_) => value >= 0,
+            Self::Positive(_) => value > 0,
+        }
+    }
+
+    /// The condition under which the program faults, like `x < 0`.
+    fn violation(&self) -> String {
+        match self {
+            Self::NonZero(a) => format!("{} == 0", a),
+            Self::NonNegative(a) => format!("{} < 0", a),
+            Self::Positive(a) => format!("{} <= 0", a),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Statement {
+    /// `var = value`
+    Copy(char, Operand),
+    /// `var = a op b`
+    Apply(char, Op, Operand, Operand),
+    /// Faults unless the guard holds.
+    Check(Guard),
+}
+
+/// Straight-line code computing the same `z` as an ALU program, with constants folded and
+/// assignments which never get read removed. It can be run directly or turned into Rust or C
+/// source. Overflow is left to the target language, the ALU wraps around.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compiled {
+    pub statements: Vec<Statement>,
+    /// The number of `inp` instructions, even those whose value isn't used.
+    pub inputs: usize,
+    /// The final value of `z`, or `None` if the program always faults.
+    pub result: Option<Operand>,
+}
+
+/// The conditions `a op b` needs to not fault.
+fn guards(op: Op, a: Operand, b: Operand) -> Vec<Guard> {
+    match op {
+        Op::Div => vec![Guard::NonZero(b)],
+        Op::Mod => vec![Guard::NonNegative(a), Guard::Positive(b)],
+        _ => vec![],
+    }
+}
+
+/// `a op b` if it's a constant or one of the operands, assuming its guards hold.
+fn fold(op: Op, a: Operand, b: Operand) -> Option<Operand> {
+    use Operand::Const;
+
+    match (op, a, b) {
+        (op, Const(a), Const(b)) => op.apply(a, b).ok().map(Const),
+        (Op::Add, Const(0), x) | (Op::Add, x, Const(0)) => Some(x),
+        (Op::Mul, Const(0), _) | (Op::Mul, _, Const(0)) => Some(Const(0)),
+        (Op::Mul, Const(1), x) | (Op::Mul, x, Const(1)) => Some(x),
+        (Op::Div, x, Const(1)) => Some(x),
+        (Op::Div | Op::Mod, Const(0), _) | (Op::Mod, _, Const(1)) => Some(Const(0)),
+        (Op::Eql, a, b) if a == b => Some(Const(1)),
+        _ => None,
+    }
+}
+
+/// Compiles an ALU program, see `Compiled`.
+pub fn compile(program: &[Instruction]) -> Compiled {
+    let inputs = program.iter().filter(|i| i.operation().is_none()).count();
+    let mut statements = vec![];
+    let mut next_input = 0;
+
+    // What each register holds, which is substituted wherever it's read. Only values which
+    // can't be computed at compile time are stored in a register of the compiled code and
+    // show up as `Operand::Reg`.
+    let mut values = [Operand::Const(0); 4];
+
+    for instruction in program {
+        let var = instruction.var();
+        let mut assignment = None;
+
+        let value = match instruction.operation() {
+            None => {
+                next_input += 1;
+                Operand::Input(next_input - 1)
+            }
+            Some((op, val)) => {
+                let a = values[Registers::index(var)];
+                let b = match val {
+                    VarOrConst::Const(val) => Operand::Const(*val),
+                    VarOrConst::Var(other) => values[Registers::index(*other)],
+                };
+
+                for guard in guards(op, a, b) {
+                    match guard.operand() {
+                        Operand::Const(val) if guard.holds(val) => {}
+                        Operand::Const(_) => {
+                            return Compiled {
+                                statements: vec![],
+                                inputs,
+                                result: None,
+                            }
+                        }
+                        _ => statements.push(Statement::Check(guard)),
+                    }
+                }
+
+                fold(op, a, b).unwrap_or_else(|| {
+                    assignment = Some(Statement::Apply(var, op, a, b));
+                    Operand::Reg(var)
+                })
+            }
+        };
+
+        if assignment.is_none() && value == values[Registers::index(var)] {
+            continue;
+        }
+
+        // Registers still holding the old value of `var` need their own copy of it first.
+        for other in "wxyz".chars().filter(|other| *other != var) {
+            if values[Registers::index(other)] == Operand::Reg(var) {
+                statements.push(Statement::Copy(other, Operand::Reg(var)));
+                values[Registers::index(other)] = Operand::Reg(other);
+            }
+        }
+
+        statements.extend(assignment);
+        values[Registers::index(var)] = value;
+    }
+
+    let result = values[Registers::index('z')];
+
+    Compiled {
+        statements: eliminate_dead_stores(statements, result),
+        inputs,
+        result: Some(result),
+    }
+}
+
+/// Removes the assignments whose value is overwritten or never read. Checks always stay, since
+/// they decide whether the program faults.
+fn eliminate_dead_stores(statements: Vec<Statement>, result: Operand) -> Vec<Statement> {
+    let mut live = [false; 4];
+    let read = |live: &mut [bool; 4], operands: &[Operand]| {
+        for var in operands.iter().filter_map(Operand::reg) {
+            live[Registers::index(var)] = true;
+        }
+    };
+    read(&mut live, &[result]);
+
+    let mut kept = vec![];
+
+    for statement in statements.into_iter().rev() {
+        match statement {
+            Statement::Copy(var, _) | Statement::Apply(var, _, _, _)
+                if !live[Registers::index(var)] =>
+            {
+                continue
+            }
+            Statement::Copy(var, a) => {
+                live[Registers::index(var)] = false;
+                read(&mut live, &[a]);
+            }
+            Statement::Apply(var, _, a, b) => {
+                live[Registers::index(var)] = false;
+                read(&mut live, &[a, b]);
+            }
+            Statement::Check(guard) => read(&mut live, &[guard.operand()]),
+        }
+        kept.push(statement);
+    }
+
+    kept.reverse();
+    kept
+}
+
+impl Compiled {
+    /// Runs the compiled code, returns `None` if it faults or there's not enough input.
+    pub fn run(&self, input: &[i64]) -> Option<i64> {
+        if input.len() < self.inputs {
+            return None;
+        }
+
+        let mut registers = Registers::default();
+        let get = |registers: &Registers, operand: Operand| match operand {
+            Operand::Const(val) => val,
+            Operand::Input(n) => input[n],
+            Operand::Reg(var) => registers.get(var),
+        };
+
+        for statement in &self.statements {
+            match *statement {
+                Statement::Copy(var, a) => registers.set(var, get(&registers, a)),
+                Statement::Apply(var, op, a, b) => {
+                    let value = op.apply(get(&registers, a), get(&registers, b)).ok()?;
+                    registers.set(var, value);
+                }
+                Statement::Check(guard) => {
+                    if !guard.holds(get(&registers, guard.operand())) {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        self.result.map(|result| get(&registers, result))
+    }
+
+    /// A Rust function `fn name(input: &[i64; inputs]) -> Option<i64>` returning `z`, or `None`
+    /// if the program faults.
+    pub fn to_rust(&self, name: &str) -> String {
+        let operands = self
+            .statements
+            .iter()
+            .flat_map(|statement| match statement {
+                Statement::Copy(_, a) => vec![*a],
+                Statement::Apply(_, _, a, b) => vec![*a, *b],
+                Statement::Check(guard) => vec![guard.operand()],
+            });
+        let reads_input = operands
+            .chain(self.result)
+            .any(|a| matches!(a, Operand::Input(_)));
+
+        let mut lines = vec![format!(
+            "pub fn {}({}: &[i64; {}]) -> Option<i64> {{",
+            name,
+            if reads_input { "input" } else { "_input" },
+            self.inputs
+        )];
+
+        for statement in &self.statements {
+            lines.push(match statement {
+                Statement::Copy(var, a) => format!("    let {} = {};", var, rust_operand(*a)),
+                Statement::Apply(var, op, a, b) => {
+                    format!("    let {} = {};", var, rust_binary(*op, *a, *b))
+                }
+                Statement::Check(guard) => {
+                    format!(
+                        "    if {} {{\n        return None;\n    }}",
+                        guard.violation()
+                    )
+                }
+            });
+        }
+
+        lines.push(match self.result {
+            Some(result) => format!("    Some({})", rust_operand(result)),
+            None => "    None".to_string(),
+        });
+        lines.push("}".to_string());
+
+        lines.join("\n")
+    }
+
+    /// A C function `bool name(const int64_t input[inputs], int64_t *z)` storing `z`, which
+    /// returns false if the program faults.
+    pub fn to_c(&self, name: &str) -> String {
+        let mut lines = vec![
+            "#include <stdbool.h>".to_string(),
+            "#include <stdint.h>".to_string(),
+            String::new(),
+            format!(
+                "bool {}(const int64_t input[{}], int64_t *result) {{",
+                name,
+                self.inputs.max(1)
+            ),
+        ];
+
+        let assigned: String = "wxyz"
+            .chars()
+            .filter(|var| {
+                self.statements.iter().any(|statement| match statement {
+                    Statement::Copy(v, _) | Statement::Apply(v, _, _, _) => v == var,
+                    Statement::Check(_) => false,
+                })
+            })
+            .map(|var| var.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        if !assigned.is_empty() {
+            lines.push(format!("    int64_t {};", assigned));
+        }
+
+        for statement in &self.statements {
+            lines.push(match statement {
+                Statement::Copy(var, a) => format!("    {} = {};", var, c_operand(*a)),
+                Statement::Apply(var, op, a, b) => {
+                    format!("    {} = {};", var, c_binary(*op, *a, *b))
+                }
+                Statement::Check(guard) => {
+                    format!("    if ({}) return false;", guard.violation())
+                }
+            });
+        }
+
+        match self.result {
+            Some(result) => {
+                lines.push(format!("    *result = {};", c_operand(result)));
+                lines.push("    return true;".to_string());
+            }
+            None => lines.push("    return false;".to_string()),
+        }
+        lines.push("}".to_string());
+
+        lines.join("\n")
+    }
+}
+
+/// `a` in Rust, where `-9223372036854775808` can't be written as a literal on its own.
+fn rust_operand(a: Operand) -> String {
+    match a {
+        Operand::Const(i64::MIN) => "i64::MIN".to_string(),
+        a => a.to_string(),
+    }
+}
+
+/// `a op b` in Rust, wrapping around like `Op::apply`. The guards before it already ruled out
+/// faults, and `%` only sees non-negative operands, so it can't overflow.
+fn rust_binary(op: Op, a: Operand, b: Operand) -> String {
+    let (a, b_operand, b) = (rust_operand(a), b, rust_operand(b));
+
+    match (op, b_operand) {
+        (Op::Add, _) => format!("i64::wrapping_add({}, {})", a, b),
+        (Op::Mul, _) => format!("i64::wrapping_mul({}, {})", a, b),
+        // Only dividing by -1 can overflow.
+        (Op::Div, Operand::Const(divisor)) if divisor != -1 => format!("{} / {}", a, b),
+        (Op::Div, _) => format!("i64::wrapping_div({}, {})", a, b),
+        (Op::Mod, _) => format!("{} % {}", a, b),
+        (Op::Eql, _) => format!("({} == {}) as i64", a, b),
+    }
+}
+
+/// `a` in C, where `-9223372036854775808` would be the negation of an unsigned constant.
+fn c_operand(a: Operand) -> String {
+    match a {
+        Operand::Const(i64::MIN) => "INT64_MIN".to_string(),
+        a => a.to_string(),
+    }
+}
+
+/// `a op b` in C, wrapping around like `Op::apply`. Signed overflow is undefined in C, so sums
+/// and products are computed on `uint64_t`, and dividing by -1 is a negation on `uint64_t`.
+fn c_binary(op: Op, a: Operand, b: Operand) -> String {
+    let (a, b_operand, b) = (c_operand(a), b, c_operand(b));
+    let negated = format!("(int64_t)(0 - (uint64_t){})", a);
+
+    match (op, b_operand) {
+        (Op::Add, _) => format!("(int64_t)((uint64_t){} + (uint64_t){})", a, b),
+        (Op::Mul, _) => format!("(int64_t)((uint64_t){} * (uint64_t){})", a, b),
+        (Op::Div, Operand::Const(-1)) => negated,
+        (Op::Div, Operand::Const(_)) => format!("{} / {}", a, b),
+        (Op::Div, _) => format!("{} == -1 ? {} : {} / {}", b, negated, a, b),
+        (Op::Mod, _) => format!("{} % {}", a, b),
+        (Op::Eql, _) => format!("{} == {}", a, b),
+    }
+}
+
+/// A register's value as an expression over the input digits and the registers at the start of a
+/// block, see `execute_symbolic`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(i64),
+    /// The `n`th digit of the input, assumed to be between 1 and 9.
+    Input(usize),
+    /// A register at the start of the block.
+    Reg(char),
+    Binary(Op, Box<Expr>, Box<Expr>),
+    /// `if cond { then } else { otherwise }`, where `cond` is an `eql`.
+    If(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+/// The most `eql`s `Expr::split` turns into branches.
+const MAX_SPLITS: usize = 4;
+
+const FULL_RANGE: (i64, i64) = (i64::MIN, i64::MAX);
+
+/// The smallest and largest results of `f` for any operands in the ranges `a` and `b`, if `f` is
+/// monotonic in both.
+fn corners<F>(a: (i64, i64), b: (i64, i64), f: F) -> (i64, i64)
+where
+    F: Fn(i64, i64) -> Option<i64>,
+{
+    let values: Option<Vec<i64>> = [(a.0, b.0), (a.0, b.1), (a.1, b.0), (a.1, b.1)]
+        .into_iter()
+        .map(|(a, b)| f(a, b))
+        .collect();
+
+    match values {
+        Some(values) => (*values.iter().min().unwrap(), *values.iter().max().unwrap()),
+        None => FULL_RANGE,
+    }
+}
+
+impl Expr {
+    /// Builds `a op b`, simplified as far as the ranges of `a` and `b` allow.
+    pub fn binary(op: Op, a: Expr, b: Expr) -> Expr {
+        use Expr::Const;
+
+        match (op, a, b) {
+            (op, Const(a), Const(b)) if op.apply(a, b).is_ok() => Const(op.apply(a, b).unwrap()),
+            // Constants go to the right, so they can be combined below.
+            (Op::Add | Op::Mul | Op::Eql, Const(a), b) => Self::binary(op, b, Const(a)),
+            (Op::Add, a, Const(0)) => a,
+            (Op::Add, Expr::Binary(Op::Add, a, c1), Const(c2)) if c1.as_const().is_some() => {
+                Self::binary(Op::Add, *a, Const(c1.as_const().unwrap().wrapping_add(c2)))
+            }
+            // `a + (b + c)` is written as `a + b + c`.
+            (Op::Add, a, Expr::Binary(Op::Add, b, c)) if c.as_const().is_some() => {
+                Self::binary(Op::Add, Self::binary(Op::Add, a, *b), *c)
+            }
+            (Op::Mul, _, Const(0)) => Const(0),
+            (Op::Mul, a, Const(1)) | (Op::Div, a, Const(1)) => a,
+            (Op::Mod, _, Const(1)) => Const(0),
+            (Op::Div, a, Const(b)) if b > 0 && a.range().0 > -b && a.range().1 < b => Const(0),
+            (Op::Mod, a, Const(b)) if a.range().0 >= 0 && a.range().1 < b => a,
+            (Op::Eql, a, b) if a == b => Const(1),
+            (Op::Eql, a, b) if a.range().1 < b.range().0 || b.range().1 < a.range().0 => Const(0),
+            (op, a, b) => Expr::Binary(op, Box::new(a), Box::new(b)),
+        }
+    }
+
+    fn as_const(&self) -> Option<i64> {
+        match self {
+            Self::Const(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    /// The smallest and largest possible value, assuming the program doesn't fault.
+    pub fn range(&self) -> (i64, i64) {
+        match self {
+            Self::Const(val) => (*val, *val),
+            Self::Input(_) => (1, 9),
+            Self::Reg(_) => FULL_RANGE,
+            Self::If(_, then, otherwise) => {
+                let (then, otherwise) = (then.range(), otherwise.range());
+                (then.0.min(otherwise.0), then.1.max(otherwise.1))
+            }
+            Self::Binary(op, a, b) => {
+                let (a, b) = (a.range(), b.range());
+
+                match op {
+                    Op::Add => corners(a, b, i64::checked_add),
+                    Op::Mul => corners(a, b, i64::checked_mul),
+                    Op::Div if b.0 == b.1 && b.0 != 0 => corners(a, b, i64::checked_div),
+                    Op::Div => FULL_RANGE,
+                    // Anything else faults.
+                    Op::Mod => (0, a.1.min(b.1.saturating_sub(1)).max(0)),
+                    Op::Eql => (0, 1),
+                }
+            }
+        }
+    }
+
+    /// Replaces every occurrence of `from` and simplifies the result.
+    pub fn replace(&self, from: &Expr, to: &Expr) -> Expr {
+        if self == from {
+            return to.clone();
+        }
+
+        match self {
+            Self::Binary(op, a, b) => Self::binary(*op, a.replace(from, to), b.replace(from, to)),
+            Self::If(cond, then, otherwise) => Self::If(
+                Box::new(cond.replace(from, to)),
+                Box::new(then.replace(from, to)),
+                Box::new(otherwise.replace(from, to)),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    /// An `eql` which doesn't contain any other `eql`.
+    fn innermost_eql(&self) -> Option<&Expr> {
+        match self {
+            Self::Binary(op, a, b) => a
+                .innermost_eql()
+                .or_else(|| b.innermost_eql())
+                .or_else(|| (*op == Op::Eql).then_some(self)),
+            _ => None,
+        }
+    }
+
+    /// Turns the `eql`s whose result isn't known into `if`s, so every branch is plain arithmetic.
+    pub fn split(&self) -> Expr {
+        self.split_at_most(MAX_SPLITS)
+    }
+
+    fn split_at_most(&self, splits: usize) -> Expr {
+        let cond = match self.innermost_eql() {
+            Some(cond) if splits > 0 => cond,
+            _ => return self.clone(),
+        };
+
+        let then = self
+            .replace(cond, &Expr::Const(1))
+            .split_at_most(splits - 1);
+        let otherwise = self
+            .replace(cond, &Expr::Const(0))
+            .split_at_most(splits - 1);
+
+        if then == otherwise {
+            then
+        } else {
+            Expr::If(Box::new(cond.clone()), Box::new(then), Box::new(otherwise))
+        }
+    }
+
+    /// Computes the value, returns `None` if it faults.
+    pub fn eval(&self, registers: &Registers, input: &[i64]) -> Option<i64> {
+        match self {
+            Self::Const(val) => Some(*val),
+            Self::Input(n) => input.get(*n).copied(),
+            Self::Reg(var) => Some(registers.get(*var)),
+            Self::Binary(op, a, b) => op
+                .apply(a.eval(registers, input)?, b.eval(registers, input)?)
+                .ok(),
+            Self::If(cond, then, otherwise) => match cond.eval(registers, input)? {
+                0 => otherwise.eval(registers, input),
+                _ => then.eval(registers, input),
+            },
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Binary(Op::Eql, _, _) => 1,
+            Self::Binary(Op::Add, _, _) => 2,
+            Self::Binary(_, _, _) => 3,
+            Self::If(_, _, _) => 0,
+            _ => 4,
+        }
+    }
+}
+
+/// Formats the expression like Rust code, with `d[n]` for the `n`th input digit.
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operand = |f: &mut fmt::Formatter<'_>, e: &Expr, min: u8| {
+            if e.precedence() < min {
+                write!(f, "({})", e)
+            } else {
+                write!(f, "{}", e)
+            }
+        };
+
+        match self {
+            Self::Const(val) => write!(f, "{}", val),
+            Self::Input(n) => write!(f, "d[{}]", n),
+            Self::Reg(var) => write!(f, "{}", var),
+            Self::If(cond, then, otherwise) => {
+                write!(f, "if {} {{ {} }} else {{ {} }}", cond, then, otherwise)
+            }
+            Self::Binary(op, a, b) => {
+                let precedence = self.precedence();
+                operand(f, a, precedence)?;
+
+                match (op, &**b) {
+                    (Op::Add, Expr::Const(b)) if *b < 0 && *b != i64::MIN => {
+                        return write!(f, " - {}", -b)
+                    }
+                    (Op::Add, _) => write!(f, " + ")?,
+                    (Op::Mul, _) => write!(f, " * ")?,
+                    (Op::Div, _) => write!(f, " / ")?,
+                    (Op::Mod, _) => write!(f, " % ")?,
+                    (Op::Eql, _) => write!(f, " == ")?,
+                }
+
+                operand(f, b, precedence + 1)
+            }
+        }
+    }
+}
+
+/// Runs a block symbolically, the first `inp` reads digit `first_input`. Returns the registers
+/// at the end of the block in terms of those at its start.
+pub fn execute_symbolic(block: &[Instruction], first_input: usize) -> [Expr; 4] {
+    let mut registers = ['w', 'x', 'y', 'z'].map(Expr::Reg);
+    let mut next_input = first_input;
+
+    for instruction in block {
+        let var = Registers::index(instruction.var());
+
+        registers[var] = match instruction.operation() {
+            None => {
+                next_input += 1;
+                Expr::Input(next_input - 1)
+            }
+            Some((op, val)) => {
+                let b = match val {
+                    VarOrConst::Const(val) => Expr::Const(*val),
+                    VarOrConst::Var(other) => registers[Registers::index(*other)].clone(),
+                };
+                Expr::binary(op, registers[var].clone(), b)
+            }
+        };
+    }
+
+    registers
+}
+
+/// The simplified `z` at the end of every `inp` block, in terms of `z` at its start, one block
+/// per line.
+pub fn describe_blocks(program: &[Instruction]) -> String {
+    let mut first_input = 0;
+
+    split_blocks(program)
+        .iter()
+        .enumerate()
+        .map(|(i, block)| {
+            let [.., z] = execute_symbolic(block, first_input);
+            first_input += block.iter().filter(|i| i.operation().is_none()).count();
+
+            format!("block {:2}: z = {}", i + 1, z.split())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn program(source: &str) -> Vec<Instruction> {
+        Day24::parse(source).unwrap()
+    }
+
+    #[test]
+    fn test_step_and_breakpoints() {
+        // Converts the input into binary, from the example of the puzzle.
+        let program = program(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\n\
+             div w 2\nmod w 2",
+        );
+        let mut alu = Alu::new(&program, &[13]);
+
+        alu.step().unwrap();
+        assert_eq!(alu.registers().get('w'), 13);
+
+        alu.add_breakpoint(6);
+        assert_eq!(alu.run(), Ok(Stop::Breakpoint(6)));
+        assert_eq!(alu.registers().to_string(), "w=6 x=0 y=0 z=1");
+        assert_eq!(alu.run(), Ok(Stop::Finished));
+        assert_eq!(alu.registers().to_string(), "w=1 x=1 y=0 z=1");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let program = program("inp w\nadd z w\ninp w\nmul z 3\nadd z w");
+
+        assert_eq!(
+            dump_blocks(&program, &[4, 5]),
+            Ok("block  1: w=4 x=0 y=0 z=4\nblock  2: w=5 x=0 y=0 z=17".to_string())
+        );
+    }
+
+    #[test]
+    fn test_faults() {
+        let fault = |source: &str, input: &[i64]| {
+            Alu::new(&program(source), input)
+                .run()
+                .map_err(|e| (e.pc, e.fault))
+        };
+
+        assert_eq!(
+            fault("inp x\ndiv x y", &[1]),
+            Err((1, Fault::DivisionByZero))
+        );
+        assert_eq!(fault("mod x 0", &[]), Err((0, Fault::ModuloByZero)));
+        assert_eq!(
+            fault("inp x\nmod x 3", &[-1]),
+            Err((1, Fault::NegativeModulo))
+        );
+        assert_eq!(fault("inp x\ninp y", &[1]), Err((1, Fault::MissingInput)));
+    }
+
+    /// Generates MONAD source with the given constants.
+    fn monad(blocks: &[(i64, i64, i64)]) -> String {
+        blocks
+            .iter()
+            .map(|(div, check, offset)| {
+                format!(
+                    "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+                     mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\n\
+                     add y {}\nmul y x\nadd z y\n",
+                    div, check, offset
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_solve_monad() {
+        let program = program(&monad(&[
+            (1, 12, 4),
+            (1, 11, 0),
+            (26, -3, 2),
+            (26, -7, 5),
+            (1, 10, 8),
+            (26, -8, 1),
+        ]));
+        let blocks = extract_blocks(&program).unwrap();
+        let constraints = constraints(&blocks).unwrap();
+
+        assert_eq!(
+            constraints
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>(),
+            vec!["d[2] == d[1] - 3", "d[3] == d[0] - 3", "d[5] == d[4]"]
+        );
+
+        // Compare with trying every model number.
+        let valid: Vec<Vec<i64>> = (0..6)
+            .map(|_| 1..=9)
+            .multi_cartesian_product()
+            .filter(|digits| evaluate_blocks(&blocks, digits) == 0)
+            .collect();
+        let (max, min) = solve_monad(&program).unwrap();
+
+        assert_eq!(&max, valid.last().unwrap());
+        assert_eq!(&min, valid.first().unwrap());
+        assert_eq!(check_model_number(&program, &max), Some(996699));
+        assert_eq!(check_model_number(&program, &min), Some(441111));
+
+        // 9 + 24 doesn't fit into one base 26 digit, so the constraints don't hold. Only 11 is
+        // accepted, which the search finds.
+        let overflowing = Day24::parse(&monad(&[(1, 12, 24), (26, -24, 0)])).unwrap();
+        assert!(extract_blocks(&overflowing).is_some());
+        assert_eq!(solve_monad(&overflowing), None);
+        assert_eq!(Day24::part_1(&overflowing), Answer::Found(11));
+        assert_eq!(Day24::part_2(&overflowing), Answer::Found(11));
+    }
+
+    #[test]
+    fn test_not_monad() {
+        assert_eq!(extract_blocks(&program("inp w\nadd z w")), None);
+    }
+
+    #[test]
+    fn test_alu_search() {
+        let stack = program(&monad(&[
+            (1, 12, 4),
+            (1, 11, 0),
+            (26, -3, 2),
+            (26, -7, 5),
+            (1, 10, 8),
+            (26, -8, 1),
+        ]));
+        let (max, min) = solve_monad(&stack).unwrap();
+
+        let search = AluSearch::new(&stack);
+        assert_eq!(search.max(), Some(max.clone()));
+        assert_eq!(search.min(), Some(min.clone()));
+
+        let search = search.with_z_pruning();
+        assert_eq!(search.max(), Some(max));
+        assert_eq!(search.min(), Some(min));
+
+        // Accepts inputs where the first digit is the sum of the other two, with a faulting `div`
+        // for a third digit of 9.
+        let other = program(
+            "add x 1\ninp z\ninp w\nadd y w\nmul y -1\nadd z y\ninp w\nadd w -9\ndiv x w\n\
+             add w 9\nmul w -1\nadd z w",
+        );
+        let search = AluSearch::new(&other);
+        assert_eq!(search.max(), Some(vec![9, 8, 1]));
+        assert_eq!(search.min(), Some(vec![2, 1, 1]));
+        assert_eq!(AluSearch::new(&other[..3]).min(), None);
+    }
+
+    #[test]
+    fn test_fallback_search() {
+        // `add z -509` makes `z` smaller, so pruning on `z` would miss 59.
+        let instructions =
+            Day24::parse("inp w\nadd z w\nmul z 100\ninp w\nadd z w\nadd z -509").unwrap();
+
+        assert_eq!(Day24::part_1(&instructions), Answer::Found(59));
+        assert_eq!(Day24::part_2(&instructions), Answer::Found(59));
+
+        assert_eq!(
+            crate::solve_day(24, "inp w\nadd z w", None)
+                .unwrap()
+                .unwrap(),
+            vec![
+                (1, "none, no model number is accepted".into()),
+                (2, "none, no model number is accepted".into())
+            ]
+        );
+    }
+
+    const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+    /// Random digits from 1 to 9 and the model number they make up, from a xorshift generator so
+    /// they're the same on every run.
+    fn random_input(state: &mut u64, len: usize) -> (Vec<i64>, String) {
+        let digits: Vec<i64> = (0..len)
+            .map(|_| {
+                *state ^= *state << 13;
+                *state ^= *state >> 7;
+                *state ^= *state << 17;
+                (*state % 9 + 1) as i64
+            })
+            .collect();
+        let number = digits.iter().map(|d| d.to_string()).collect();
+
+        (digits, number)
+    }
+
+    #[test]
+    fn test_compile() {
+        let compiled = compile(&program(
+            "inp x\ninp y\nmul z 0\nadd z x\nmod z y\ndiv w 1\nadd w 7\neql x w\nmul z x\nadd z -3",
+        ));
+
+        assert_eq!(
+            compiled.to_rust("f"),
+            "pub fn f(input: &[i64; 2]) -> Option<i64> {
+    if input[0] < 0 {
+        return None;
+    }
+    if input[1] <= 0 {
+        return None;
+    }
+    let z = input[0] % input[1];
+    let x = (input[0] == 7) as i64;
+    let z = i64::wrapping_mul(z, x);
+    let z = i64::wrapping_add(z, -3);
+    Some(z)
+}"
+        );
+        assert_eq!(
+            compiled.to_c("f"),
+            "#include <stdbool.h>
+#include <stdint.h>
+
+bool f(const int64_t input[2], int64_t *result) {
+    int64_t x, z;
+    if (input[0] < 0) return false;
+    if (input[1] <= 0) return false;
+    z = input[0] % input[1];
+    x = input[0] == 7;
+    z = (int64_t)((uint64_t)z * (uint64_t)x);
+    z = (int64_t)((uint64_t)z + (uint64_t)-3);
+    *result = z;
+    return true;
+}"
+        );
+
+        let dividing = compile(&program("inp x\ninp y\ndiv x y\nadd z x"));
+        assert_eq!(
+            dividing.to_rust("g").lines().nth(4),
+            Some("    let x = i64::wrapping_div(input[0], input[1]);")
+        );
+        assert_eq!(
+            dividing.to_c("g").lines().nth(6),
+            Some(
+                "    x = input[1] == -1 ? (int64_t)(0 - (uint64_t)input[0]) : input[0] / input[1];"
+            )
+        );
+
+        let faulting = compile(&program("inp w\nadd z w\nmod x 0"));
+        assert_eq!(faulting.result, None);
+        assert_eq!(
+            faulting.to_rust("f"),
+            "pub fn f(_input: &[i64; 1]) -> Option<i64> {\n    None\n}"
+        );
+    }
+
+    /// The programs of `test_compile_agrees_with_alu`.
+    fn compile_programs() -> Vec<String> {
+        vec![
+            monad(&[
+                (1, 12, 4),
+                (1, 11, 0),
+                (26, -3, 2),
+                (26, -7, 5),
+                (1, 10, 8),
+                (26, -8, 1),
+            ]),
+            // Faults for some inputs and uses registers holding the value of another one.
+            "inp x\nadd y x\ninp x\nadd w 1\nadd w x\nadd w -5\ndiv y w\nmul x y\ninp z\n\
+             add z -4\nmod z x\nadd z y\ninp y\neql y x\nadd z y\ninp w\ninp x\nmul x w"
+                .to_string(),
+            // Overflows in the sum and product, and divides the smallest value by -1 if the
+            // second digit is 1.
+            "inp w\nadd z 9223372036854775807\nadd z w\nmul z 3\ninp x\nmul x -1\n\
+             add y -9223372036854775807\nadd y -1\ndiv y x\nadd z y"
+                .to_string(),
+        ]
+    }
+
+    /// `to_rust` of each of `compile_programs`, as `program_0`, `program_1` and so on.
+    fn generate_programs() -> String {
+        compile_programs()
+            .iter()
+            .enumerate()
+            .map(|(i, source)| compile(&program(source)).to_rust(&format!("program_{}", i)))
+            .join("\n\n")
+    }
+
+    #[test]
+    fn test_compile_agrees_with_alu() {
+        let run_generated = |i: usize, digits: &[i64]| match i {
+            0 => generated::program_0(digits.try_into().unwrap()),
+            1 => generated::program_1(digits.try_into().unwrap()),
+            _ => generated::program_2(digits.try_into().unwrap()),
+        };
+
+        let mut state = SEED;
+
+        for (i, source) in compile_programs().iter().enumerate() {
+            let program = program(source);
+            let compiled = compile(&program);
+
+            for _ in 0..1000 {
+                let (digits, number) = random_input(&mut state, compiled.inputs);
+                let expected = evaluate(&number, &program).ok();
+
+                assert_eq!(compiled.run(&digits), expected, "{}", number);
+                assert_eq!(run_generated(i, &digits), expected, "{}", number);
+            }
+        }
+    }
+
+    /// The puzzle input, a real MONAD.
+    const MONAD: &str = include_str!("../inputs/day24.txt");
+
+    /// The output of `to_rust` for `MONAD` and for `compile_programs`, see
+    /// `test_generated_rust_is_up_to_date`.
+    mod generated {
+        include!("../tests/fixtures/day24_monad.rs");
+        include!("../tests/fixtures/day24_programs.rs");
+    }
+
+    #[test]
+    fn test_generated_rust_is_up_to_date() {
+        assert_eq!(
+            include_str!("../tests/fixtures/day24_monad.rs").trim_end(),
+            compile(&program(MONAD)).to_rust("compiled_monad"),
+            "tests/fixtures/day24_monad.rs has to be generated again"
+        );
+        assert_eq!(
+            include_str!("../tests/fixtures/day24_programs.rs").trim_end(),
+            generate_programs(),
+            "tests/fixtures/day24_programs.rs has to be generated again"
+        );
+    }
+
+    #[test]
+    fn test_generated_rust_agrees_with_alu() {
+        let program = program(MONAD);
+        let mut state = SEED;
+
+        for _ in 0..1000 {
+            let (digits, number) = random_input(&mut state, 14);
+            let input: [i64; 14] = digits.try_into().unwrap();
+
+            assert_eq!(
+                generated::compiled_monad(&input),
+                evaluate(&number, &program).ok(),
+                "{}",
+                number
+            );
+        }
+    }
+
+    #[test]
+    fn test_describe_blocks() {
+        let program = program(&monad(&[(1, 12, 4), (26, -3, 2)]));
+
+        assert_eq!(
+            describe_blocks(&program),
+            "block  1: z = z * 26 + d[0] + 4
+block  2: z = if z % 26 - 3 == d[1] { z / 26 } else { z / 26 * 26 + d[1] + 2 }"
+        );
+        assert_eq!(
+            describe_blocks(&self::program(
+                "inp w\nmul x 0\nadd x w\nmod x 10\nmul x 3\ndiv y 1\nadd z x"
+            )),
+            "block  1: z = z + d[0] * 3"
+        );
+    }
+
+    #[test]
+    fn test_symbolic_agrees_with_alu() {
+        let sources = [
+            monad(&[(1, 12, 4), (1, 11, 0), (26, -3, 2), (26, -7, 5)]),
+            "inp x\nadd y x\ninp x\nadd w 1\nadd w x\nadd w -5\ndiv y w\nmul x y\ninp z\n\
+             add z -4\nmod z x\nadd z y\ninp y\neql y x\nadd z y\ninp w\ninp x\nmul x w"
+                .to_string(),
+        ];
+
+        let mut state = SEED;
+
+        for source in &sources {
+            let program = program(source);
+            let blocks: Vec<[Expr; 4]> = split_blocks(&program)
+                .iter()
+                .enumerate()
+                .map(|(i, block)| execute_symbolic(block, i).map(|e| e.split()))
+                .collect();
+
+            for _ in 0..1000 {
+                let (digits, number) = random_input(&mut state, blocks.len());
+
+                let mut registers = Some(Registers::default());
+                for block in &blocks {
+                    registers = registers.and_then(|r| {
+                        let mut next = Registers::default();
+                        for (var, expr) in "wxyz".chars().zip(block) {
+                            next.set(var, expr.eval(&r, &digits)?);
+                        }
+                        Some(next)
+                    });
+                }
+
+                // Simplifying may remove faults, but never changes a result.
+                if let Ok(z) = evaluate(&number, &program) {
+                    assert_eq!(registers.map(|r| r.get('z')), Some(z), "{}", number);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::grid::{Grid, Pos};
+use crate::parse::ParseError;
+use crate::Solution;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "'>', 'v' or '.'", |c| ">v.".contains(c).then_some(c))
+    }
+
+    fn part_1(grid: &Self::Input) -> usize {
+        let mut grid = grid.clone();
+
+        let mut iterations = 1;
+        // > first, v last
+        while move_herd(&mut grid, '>', (0, 1)) | move_herd(&mut grid, 'v', (1, 0)) {
+            iterations += 1;
+        }
+
+        iterations
+    }
+
+    // There's no second puzzle on the last day.
+    fn part_2(_: &Self::Input) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+/// Moves every sea cucumber of `herd` one step in `direction` if the spot there is free. All of
+/// them look at the grid before anyone moved. Returns whether any of them moved.
+pub fn move_herd(grid: &mut Grid<char>, herd: char, direction: (isize, isize)) -> bool {
+    let moves: Vec<(Pos, Pos)> = grid
+        .iter()
+        .filter(|(_, c)| **c == herd)
+        .map(|(pos, _)| (pos, grid.offset_wrapping(pos, direction)))
+        .filter(|(_, to)| grid[*to] == '.')
+        .collect();
+
+    for (from, to) in &moves {
+        grid[*from] = '.';
+        grid[*to] = herd;
+    }
+
+    !moves.is_empty()
+}
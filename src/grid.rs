@@ -0,0 +1,354 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::ParseError;
+
+/// A position in a grid as `(row, col)`.
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// The horizontal and vertical neighbours of `pos` inside a `width` x `height` rectangle, see
+/// `Grid::neighbours_4`. For shapes which don't store their cells in a `Grid`.
+pub fn neighbours_4(width: usize, height: usize, pos: Pos) -> impl Iterator<Item = Pos> {
+    OFFSETS_4
+        .iter()
+        .filter_map(move |d| offset(width, height, pos, *d))
+}
+
+/// Moves `pos` by `delta`, returns `None` if that leaves the `width` x `height` rectangle.
+fn offset(
+    width: usize,
+    height: usize,
+    (row, col): Pos,
+    (d_row, d_col): (isize, isize),
+) -> Option<Pos> {
+    let pos = (
+        row.checked_add_signed(d_row)?,
+        col.checked_add_signed(d_col)?,
+    );
+
+    (pos.0 < height && pos.1 < width).then_some(pos)
+}
+
+/// A rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid by calling `f` for every position.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(Pos) -> T,
+    {
+        Self {
+            cells: (0..height)
+                .flat_map(|row| (0..width).map(move |col| (row, col)))
+                .map(&mut f)
+                .collect(),
+            width,
+            height,
+        }
+    }
+
+    /// Parses one cell per character, `f` returns `None` for characters which aren't allowed.
+    /// `expected` describes the allowed characters in error messages.
+    pub fn parse<F>(input: &str, expected: &str, f: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (i, line) in input.lines().enumerate() {
+            let on_line = |e: ParseError| e.on_line(i + 1);
+
+            for (j, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    on_line(ParseError::new(
+                        line,
+                        &line[j..j + c.len_utf8()],
+                        format!("expected {}", expected),
+                    ))
+                })?;
+                cells.push(cell);
+            }
+
+            let line_width = line.chars().count();
+
+            match width {
+                None => width = Some(line_width),
+                Some(w) if w < line_width => {
+                    let (j, _) = line.char_indices().nth(w).unwrap();
+                    return Err(on_line(ParseError::new(
+                        line,
+                        &line[j..],
+                        format!("expected a row of {} cells", w),
+                    )));
+                }
+                Some(w) if w > line_width => {
+                    return Err(on_line(ParseError::end_of_line(
+                        line,
+                        format!("expected a row of {} cells", w),
+                    )));
+                }
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `pos` by `delta`, returns `None` if that leaves the grid.
+    pub fn offset(&self, pos: Pos, delta: (isize, isize)) -> Option<Pos> {
+        offset(self.width, self.height, pos, delta)
+    }
+
+    /// Moves `pos` by `delta`, wrapping around the edges as if the grid was a torus.
+    pub fn offset_wrapping(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Pos {
+        (
+            (row as isize + d_row).rem_euclid(self.height as isize) as usize,
+            (col as isize + d_col).rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    /// The horizontal and vertical neighbours of `pos` which are inside the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        neighbours_4(self.width, self.height, pos)
+    }
+
+    /// The horizontal, vertical and diagonal neighbours of `pos` which are inside the grid.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8.iter().filter_map(move |d| self.offset(pos, *d))
+    }
+
+    /// The horizontal and vertical neighbours of `pos`, wrapping around the edges.
+    pub fn neighbours_4_wrapping(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4.iter().map(move |d| self.offset_wrapping(pos, *d))
+    }
+
+    /// The horizontal, vertical and diagonal neighbours of `pos`, wrapping around the edges.
+    pub fn neighbours_8_wrapping(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_8.iter().map(move |d| self.offset_wrapping(pos, *d))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// All cells together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells[col..].iter().step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Renders the grid with one character per cell.
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(Pos, &T) -> char,
+    {
+        self.rows()
+            .enumerate()
+            .map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(col, cell)| f((row, col), cell))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} out of bounds of {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} out of bounds of {}x{} grid",
+                pos, width, height
+            )
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let error = Grid::parse("123\n4x6\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = Grid::parse("123\n45\n", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789");
+
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours_8((2, 2)).count(), 3);
+        assert_eq!(
+            grid.neighbours_4_wrapping((0, 0)).collect::<Vec<_>>(),
+            vec![(2, 0), (1, 0), (0, 2), (0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("12\n34\n56");
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2], &[3, 4], &[5, 6]]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![vec![1, 3, 5], vec![2, 4, 6]]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::fmt::{self, Display};
+
+use parse::ParseError;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod grid;
+pub mod parse;
+pub mod search;
+
+/// A single day of the calendar. `parse` turns the puzzle input into whatever representation both
+/// parts share, `part_1` and `part_2` compute the answers from it.
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Self::Output1;
+
+    fn part_2(input: &Self::Input) -> Self::Output2;
+}
+
+/// An answer which doesn't exist for every input, `None` says why there's none for this one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer<T> {
+    Found(T),
+    None(String),
+}
+
+impl<T: Display> Display for Answer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Found(answer) => write!(f, "{}", answer),
+            Answer::None(reason) => write!(f, "none, {}", reason),
+        }
+    }
+}
+
+/// Parses `input` and computes the answers of the requested part (or both parts if `part` is
+/// `None`). Each answer is paired with the number of its part.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Vec<(u8, String)>, ParseError> {
+    let input = S::parse(input)?;
+
+    let mut answers = vec![];
+
+    if part != Some(2) {
+        answers.push((1, S::part_1(&input).to_string()));
+    }
+    if part != Some(1) {
+        answers.push((2, S::part_2(&input).to_string()));
+    }
+
+    Ok(answers)
+}
+
+/// Same as `solve`, but picks the solution by the number of its day. Returns `None` if there's no
+/// solution for `day`.
+pub fn solve_day(
+    day: u8,
+    input: &str,
+    part: Option<u8>,
+) -> Option<Result<Vec<(u8, String)>, ParseError>> {
+    let answers = match day {
+        1 => solve::<day01::Day01>(input, part),
+        2 => solve::<day02::Day02>(input, part),
+        3 => solve::<day03::Day03>(input, part),
+        4 => solve::<day04::Day04>(input, part),
+        5 => solve::<day05::Day05>(input, part),
+        6 => solve::<day06::Day06>(input, part),
+        7 => solve::<day07::Day07>(input, part),
+        8 => solve::<day08::Day08>(input, part),
+        9 => solve::<day09::Day09>(input, part),
+        10 => solve::<day10::Day10>(input, part),
+        11 => solve::<day11::Day11>(input, part),
+        12 => solve::<day12::Day12>(input, part),
+        13 => solve::<day13::Day13>(input, part),
+        14 => solve::<day14::Day14>(input, part),
+        15 => solve::<day15::Day15>(input, part),
+        16 => solve::<day16::Day16>(input, part),
+        17 => solve::<day17::Day17>(input, part),
+        18 => solve::<day18::Day18>(input, part),
+        19 => solve::<day19::Day19>(input, part),
+        20 => solve::<day20::Day20>(input, part),
+        21 => solve::<day21::Day21>(input, part),
+        23 => solve::<day23::Day23>(input, part),
+        24 => solve::<day24::Day24>(input, part),
+        25 => solve::<day25::Day25>(input, part),
+        _ => return None,
+    };
+
+    Some(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_input() {
+        for day in [3, 9, 10, 15, 18] {
+            let error = solve_day(day, "", None).unwrap().unwrap_err();
+
+            assert_eq!((error.line, error.column), (1, 1));
+            assert!(error.message.starts_with("expected at least"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_no_answer() {
+        let answers = |day, input| solve_day(day, input, None).unwrap().unwrap();
+
+        assert_eq!(
+            answers(10, "()\n(]"),
+            vec![(1, "57".into()), (2, "none, no line is incomplete".into())]
+        );
+        assert_eq!(
+            answers(17, "target area: x=-20..-10, y=-10..-5"),
+            vec![
+                (1, "none, no velocity hits the target area".into()),
+                (2, "0".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_rules() {
+        let error = |day, input| {
+            solve_day(day, input, None)
+                .unwrap()
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            error(12, "A-b\nb-end"),
+            "line 3, column 1: expected a connection of the start cave, found end of line"
+        );
+        assert_eq!(
+            error(14, "NXCB\n\nNN -> C\nNC -> B\nCB -> H"),
+            "line 1, column 1: expected a pair with an insertion rule, found 'NX'"
+        );
+        assert_eq!(
+            error(14, "NC\n\nNC -> B\nNB -> N"),
+            "line 3, column 1: expected an insertion rule for BC, which this rule creates, \
+             found 'NC -> B'"
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// An error in the puzzle input. `line` and `column` are 1-based, `token` is the offending part
+/// of the line (empty if the line ended too early).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token`, which should be a slice of `line` so the column can be
+    /// derived from its position. The line number defaults to 1, see `on_line`.
+    pub fn new(line: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| *offset <= line.len())
+            .unwrap_or(0);
+
+        Self {
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error pointing right behind the end of `line`.
+    pub fn end_of_line(line: &str, message: impl Into<String>) -> Self {
+        Self::new(line, &line[line.len()..], message)
+    }
+
+    pub fn on_line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+
+    /// Creates an error pointing at the line after the last one of `input`.
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> Self {
+        Self::end_of_line("", message).on_line(input.lines().count() + 1)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        if self.token.is_empty() {
+            write!(f, ", found end of line")
+        } else {
+            write!(f, ", found '{}'", self.token)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of `input` with `f`, setting the line number of any error.
+pub fn parse_lines<'a, T, F>(input: &'a str, f: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| f(l).map_err(|e| e.on_line(i + 1)))
+        .collect()
+}
+
+pub fn number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(line, token, "expected a number"))
+}
+
+/// Parses a comma separated list of numbers like `3,4,3,1,2`.
+pub fn numbers<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    line.split(',').map(|n| number(line, n)).collect()
+}
+
+pub fn digit(line: &str, index: usize) -> Result<u32, ParseError> {
+    let c = line[index..].chars().next().unwrap();
+
+    c.to_digit(10).ok_or_else(|| {
+        ParseError::new(line, &line[index..index + c.len_utf8()], "expected a digit")
+    })
+}
+
+/// Parses a line of digits like `2199943210`.
+pub fn digits(line: &str) -> Result<Vec<u32>, ParseError> {
+    line.char_indices().map(|(i, _)| digit(line, i)).collect()
+}
+
+/// Splits `token` (a slice of `line`) at the first occurrence of `separator`.
+pub fn split_once<'a>(
+    line: &str,
+    token: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    token
+        .split_once(separator)
+        .ok_or_else(|| ParseError::new(line, token, format!("expected '{}'", separator)))
+}
+
+/// Strips `prefix` from `token` (a slice of `line`).
+pub fn strip_prefix<'a>(line: &str, token: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    token
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(line, token, format!("expected '{}'", prefix)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let line = "forward x1";
+        let error = number::<i64>(line, &line[8..]).unwrap_err();
+
+        assert_eq!(error.column, 9);
+        assert_eq!(error.token, "x1");
+        assert_eq!(
+            error.on_line(3).to_string(),
+            "line 3, column 9: expected a number, found 'x1'"
+        );
+    }
+
+    #[test]
+    fn test_end_of_line() {
+        let error = split_once("0,9", "0,9", " -> ").unwrap_err();
+        assert_eq!(error.column, 1);
+
+        let error = ParseError::end_of_line("0,9", "expected ' -> '");
+        assert_eq!(error.column, 4);
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected ' -> ', found end of line"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let error = parse_lines("1\n2\nthree\n", |l| number::<u32>(l, l)).unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 1);
+    }
+}
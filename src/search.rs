@@ -0,0 +1,153 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// A weighted graph which is explored lazily, starting at `start` until a state passes `is_goal`.
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn start(&self) -> Self::State;
+
+    /// The states reachable from `state` in one step, each with the cost of that step.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound of the cost from `state` to the nearest goal. Only used by `a_star`, which
+    /// finds a cheapest path as long as this never overestimates.
+    fn heuristic(&self, _state: &Self::State) -> usize {
+        0
+    }
+}
+
+/// A cheapest path from the start to a goal, including both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<S> {
+    pub path: Vec<S>,
+    pub cost: usize,
+}
+
+/// Finds a cheapest path to a goal, ignoring the heuristic of `problem`.
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Option<SearchResult<P::State>> {
+    search(problem, |_| 0)
+}
+
+/// Finds a cheapest path to a goal, exploring the states with the lowest cost plus heuristic first.
+pub fn a_star<P: SearchProblem>(problem: &P) -> Option<SearchResult<P::State>> {
+    search(problem, |state| problem.heuristic(state))
+}
+
+fn search<P, H>(problem: &P, heuristic: H) -> Option<SearchResult<P::State>>
+where
+    P: SearchProblem,
+    H: Fn(&P::State) -> usize,
+{
+    // States are stored once and referred to by their index, together with the cheapest known
+    // cost to reach them and the state they were reached from.
+    let mut states: Vec<P::State> = vec![];
+    let mut best: Vec<(usize, Option<usize>)> = vec![];
+    let mut indices: HashMap<P::State, usize> = HashMap::new();
+    let mut queue: BinaryHeap<Reverse<(usize, usize, usize)>> = BinaryHeap::new();
+
+    let start = problem.start();
+    queue.push(Reverse((heuristic(&start), 0, 0)));
+    indices.insert(start.clone(), 0);
+    states.push(start);
+    best.push((0, None));
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // A cheaper way to this state was found after this entry was queued.
+        if cost > best[index].0 {
+            continue;
+        }
+
+        if problem.is_goal(&states[index]) {
+            return Some(SearchResult {
+                path: path_to(index, &states, &best),
+                cost,
+            });
+        }
+
+        for (next, step_cost) in problem.successors(&states[index]) {
+            let next_cost = cost + step_cost;
+            let guess = next_cost + heuristic(&next);
+
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= best[next_index].0 {
+                        continue;
+                    }
+                    best[next_index] = (next_cost, Some(index));
+                    next_index
+                }
+                Entry::Vacant(entry) => {
+                    states.push(entry.key().clone());
+                    best.push((next_cost, Some(index)));
+                    *entry.insert(states.len() - 1)
+                }
+            };
+
+            queue.push(Reverse((guess, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+fn path_to<S: Clone>(mut index: usize, states: &[S], best: &[(usize, Option<usize>)]) -> Vec<S> {
+    let mut path = vec![states[index].clone()];
+
+    while let Some(previous) = best[index].1 {
+        path.push(states[previous].clone());
+        index = previous;
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 2 -1-> 3, with a direct but more expensive edge 0 -5-> 3.
+    struct Line;
+
+    impl SearchProblem for Line {
+        type State = u32;
+
+        fn start(&self) -> u32 {
+            0
+        }
+
+        fn successors(&self, state: &u32) -> Vec<(u32, usize)> {
+            match state {
+                0 => vec![(3, 5), (1, 1)],
+                1 => vec![(2, 1)],
+                2 => vec![(3, 1)],
+                _ => vec![],
+            }
+        }
+
+        fn is_goal(&self, state: &u32) -> bool {
+            *state == 3
+        }
+
+        fn heuristic(&self, state: &u32) -> usize {
+            3 - *state as usize
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let expected = Some(SearchResult {
+            path: vec![0, 1, 2, 3],
+            cost: 3,
+        });
+
+        assert_eq!(dijkstra(&Line), expected);
+        assert_eq!(a_star(&Line), expected);
+    }
+}
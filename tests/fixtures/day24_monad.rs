@@ -0,0 +1,184 @@
+pub fn compiled_monad(input: &[i64; 14]) -> Option<i64> {
+    let x = (10 == input[0]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_add(input[0], 2);
+    let y = i64::wrapping_mul(y, x);
+    if y < 0 {
+        return None;
+    }
+    let x = y % 26;
+    let x = i64::wrapping_add(x, 14);
+    let x = (x == input[1]) as i64;
+    let x = (x == 0) as i64;
+    let z = y;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[1], 13);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let x = i64::wrapping_add(x, 14);
+    let x = (x == input[2]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[2], 13);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -13);
+    let x = (x == input[3]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[3], 9);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let x = i64::wrapping_add(x, 10);
+    let x = (x == input[4]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[4], 15);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -13);
+    let x = (x == input[5]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[5], 3);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -7);
+    let x = (x == input[6]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[6], 6);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let x = i64::wrapping_add(x, 11);
+    let x = (x == input[7]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[7], 5);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let x = i64::wrapping_add(x, 10);
+    let x = (x == input[8]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[8], 16);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let x = i64::wrapping_add(x, 13);
+    let x = (x == input[9]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[9], 1);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -4);
+    let x = (x == input[10]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[10], 6);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -9);
+    let x = (x == input[11]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[11], 3);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -13);
+    let x = (x == input[12]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[12], 7);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -9);
+    let x = (x == input[13]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[13], 9);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    Some(z)
+}
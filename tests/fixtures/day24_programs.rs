@@ -0,0 +1,109 @@
+pub fn program_0(input: &[i64; 6]) -> Option<i64> {
+    let x = (12 == input[0]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_add(input[0], 4);
+    let y = i64::wrapping_mul(y, x);
+    if y < 0 {
+        return None;
+    }
+    let x = y % 26;
+    let x = i64::wrapping_add(x, 11);
+    let x = (x == input[1]) as i64;
+    let x = (x == 0) as i64;
+    let z = y;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_mul(input[1], x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -3);
+    let x = (x == input[2]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[2], 2);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -7);
+    let x = (x == input[3]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[3], 5);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let x = i64::wrapping_add(x, 10);
+    let x = (x == input[4]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[4], 8);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    if z < 0 {
+        return None;
+    }
+    let x = z % 26;
+    let z = z / 26;
+    let x = i64::wrapping_add(x, -8);
+    let x = (x == input[5]) as i64;
+    let x = (x == 0) as i64;
+    let y = i64::wrapping_mul(25, x);
+    let y = i64::wrapping_add(y, 1);
+    let z = i64::wrapping_mul(z, y);
+    let y = i64::wrapping_add(input[5], 1);
+    let y = i64::wrapping_mul(y, x);
+    let z = i64::wrapping_add(z, y);
+    Some(z)
+}
+
+pub fn program_1(input: &[i64; 6]) -> Option<i64> {
+    let w = i64::wrapping_add(1, input[1]);
+    let w = i64::wrapping_add(w, -5);
+    if w == 0 {
+        return None;
+    }
+    let y = i64::wrapping_div(input[0], w);
+    let x = i64::wrapping_mul(input[1], y);
+    let z = i64::wrapping_add(input[2], -4);
+    if z < 0 {
+        return None;
+    }
+    if x <= 0 {
+        return None;
+    }
+    let z = z % x;
+    let z = i64::wrapping_add(z, y);
+    let y = (input[3] == x) as i64;
+    let z = i64::wrapping_add(z, y);
+    Some(z)
+}
+
+pub fn program_2(input: &[i64; 2]) -> Option<i64> {
+    let z = i64::wrapping_add(9223372036854775807, input[0]);
+    let z = i64::wrapping_mul(z, 3);
+    let x = i64::wrapping_mul(input[1], -1);
+    if x == 0 {
+        return None;
+    }
+    let y = i64::wrapping_div(i64::MIN, x);
+    let z = i64::wrapping_add(z, y);
+    Some(z)
+}